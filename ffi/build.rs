@@ -0,0 +1,18 @@
+use std::env;
+use std::path::Path;
+
+/// Generates the C header into `OUT_DIR`, where a test compares it with the one in `include/`.
+fn main() {
+    let crate_dir = env::var("CARGO_MANIFEST_DIR").unwrap();
+    let out_dir = env::var("OUT_DIR").unwrap();
+    println!("cargo:rerun-if-changed=src/lib.rs");
+
+    cbindgen::Builder::new()
+        .with_crate(&crate_dir)
+        .with_language(cbindgen::Language::C)
+        .with_include_guard("ADVENT_OF_RUST_2021_H")
+        .with_header("/* Generated by cbindgen from ffi/src/lib.rs, don't edit. */")
+        .generate()
+        .expect("Cannot generate the C header")
+        .write_to_file(Path::new(&out_dir).join("advent_of_rust_2021.h"));
+}
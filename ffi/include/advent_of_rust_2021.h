@@ -0,0 +1,71 @@
+/* Generated by cbindgen from ffi/src/lib.rs, don't edit. */
+
+#ifndef ADVENT_OF_RUST_2021_H
+#define ADVENT_OF_RUST_2021_H
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * The day was solved.
+ */
+#define AOC_SOLVED 0
+
+/**
+ * Anything else went wrong.
+ */
+#define AOC_FAILED 1
+
+/**
+ * No such day or part.
+ */
+#define AOC_USAGE 2
+
+/**
+ * The input doesn't parse, or breaks what the solvers assume.
+ */
+#define AOC_PARSE_ERROR 3
+
+/**
+ * The part has no answer for the input.
+ */
+#define AOC_UNSOLVABLE 4
+
+/**
+ * Solves the part (1 or 2) of the day on the `input_len` bytes of UTF-8 at `input`.
+ *
+ * Returns `AOC_SOLVED` and points `output` at the answer, or returns one of the failures above and
+ * points `output` at its message. Either way, the string is NUL-terminated and owned by the
+ * caller, who releases it with `aoc_free_string`.
+ *
+ * # Safety
+ *
+ * `input` must be valid for reading `input_len` bytes and `output` for writing a pointer.
+ */
+int32_t aoc_solve(uint8_t day,
+                  uint8_t part,
+                  const uint8_t *input,
+                  uintptr_t input_len,
+                  char **output);
+
+/**
+ * Releases a string handed out by `aoc_solve`; does nothing on NULL.
+ *
+ * # Safety
+ *
+ * `string` must come from `aoc_solve` and must not be used, or released, afterwards.
+ */
+void aoc_free_string(char *string);
+
+/**
+ * Days that can be solved, as the first and the last one.
+ *
+ * # Safety
+ *
+ * Both pointers must be valid for writing a byte.
+ */
+void aoc_days(uint8_t *first, uint8_t *last);
+
+#endif  /* ADVENT_OF_RUST_2021_H */
@@ -0,0 +1,165 @@
+//! The solvers for callers in other languages. Every function catches the solvers' panics, so none
+//! unwinds across the boundary; failures are reported with the exit codes of the binaries.
+
+use advent_of_rust_2021::days::{with_day, Solution, Visitor};
+use advent_of_rust_2021::outcome::{self, Failure};
+use std::ffi::{c_char, CString};
+use std::{slice, str};
+
+/// The day was solved.
+pub const AOC_SOLVED: i32 = 0;
+/// Anything else went wrong.
+pub const AOC_FAILED: i32 = 1;
+/// No such day or part.
+pub const AOC_USAGE: i32 = 2;
+/// The input doesn't parse, or breaks what the solvers assume.
+pub const AOC_PARSE_ERROR: i32 = 3;
+/// The part has no answer for the input.
+pub const AOC_UNSOLVABLE: i32 = 4;
+
+struct SolvePart<'a> {
+    input: &'a str,
+    part: u8,
+}
+
+impl Visitor for SolvePart<'_> {
+    type Output = Result<String, Failure>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        let input = outcome::parse::<S>(self.input)?;
+        outcome::part::<S>(&input, self.part)
+    }
+}
+
+fn solve(day: u8, part: u8, input: &[u8]) -> Result<String, Failure> {
+    if part != 1 && part != 2 {
+        return Err(Failure::Usage(format!("There's no part {}", part)));
+    }
+    let input = str::from_utf8(input)
+        .map_err(|err| Failure::Parse(format!("Day {}: the input isn't UTF-8: {}", day, err)))?;
+
+    outcome::catch(|| with_day(day, SolvePart { input, part }))
+        .map_err(Failure::Other)?
+        .ok_or_else(|| Failure::Usage(format!("Day {} is not implemented", day)))?
+}
+
+/// Solves the part (1 or 2) of the day on the `input_len` bytes of UTF-8 at `input`.
+///
+/// Returns `AOC_SOLVED` and points `output` at the answer, or returns one of the failures above and
+/// points `output` at its message. Either way, the string is NUL-terminated and owned by the
+/// caller, who releases it with `aoc_free_string`.
+///
+/// # Safety
+///
+/// `input` must be valid for reading `input_len` bytes and `output` for writing a pointer.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    part: u8,
+    input: *const u8,
+    input_len: usize,
+    output: *mut *mut c_char,
+) -> i32 {
+    if output.is_null() {
+        return AOC_USAGE;
+    }
+    let result = if input.is_null() {
+        Err(Failure::Usage("No input given".to_owned()))
+    } else {
+        solve(day, part, slice::from_raw_parts(input, input_len))
+    };
+
+    let (code, text) = match result {
+        Ok(answer) => (AOC_SOLVED, answer),
+        Err(failure) => (failure.code(), failure.to_string()),
+    };
+    // neither answers nor messages contain NULs, but the caller gets a string regardless
+    let text = CString::new(text.replace('\0', "")).unwrap_or_default();
+    *output = text.into_raw();
+    code
+}
+
+/// Releases a string handed out by `aoc_solve`; does nothing on NULL.
+///
+/// # Safety
+///
+/// `string` must come from `aoc_solve` and must not be used, or released, afterwards.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_free_string(string: *mut c_char) {
+    if !string.is_null() {
+        drop(CString::from_raw(string));
+    }
+}
+
+/// Days that can be solved, as the first and the last one.
+///
+/// # Safety
+///
+/// Both pointers must be valid for writing a byte.
+#[no_mangle]
+pub unsafe extern "C" fn aoc_days(first: *mut u8, last: *mut u8) {
+    let days = advent_of_rust_2021::days::DAYS;
+    if !first.is_null() {
+        *first = *days.start();
+    }
+    if !last.is_null() {
+        *last = *days.end();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::ffi::CStr;
+    use std::{fs, ptr};
+
+    unsafe fn call(day: u8, part: u8, input: &[u8]) -> (i32, String) {
+        let mut output = ptr::null_mut();
+        let code = aoc_solve(day, part, input.as_ptr(), input.len(), &mut output);
+        let text = CStr::from_ptr(output).to_str().unwrap().to_owned();
+        aoc_free_string(output);
+        (code, text)
+    }
+
+    #[test]
+    fn test_codes() {
+        let message = String::new;
+        assert_eq!(AOC_FAILED, Failure::Other(message()).code());
+        assert_eq!(AOC_USAGE, Failure::Usage(message()).code());
+        assert_eq!(AOC_PARSE_ERROR, Failure::Parse(message()).code());
+        assert_eq!(AOC_UNSOLVABLE, Failure::Unsolvable(message()).code());
+    }
+
+    #[test]
+    fn test_solve() {
+        let input = fs::read("../inputs/day1/input").unwrap();
+        unsafe {
+            assert_eq!(call(1, 1, &input), (AOC_SOLVED, "1226".to_owned()));
+            assert_eq!(call(1, 2, &input), (AOC_SOLVED, "1252".to_owned()));
+            assert_eq!(call(1, 3, &input).0, AOC_USAGE);
+            assert_eq!(call(30, 1, &input).0, AOC_USAGE);
+            assert_eq!(call(4, 1, b"").0, AOC_PARSE_ERROR);
+            assert_eq!(call(1, 1, &[0xff, 0xfe]).0, AOC_PARSE_ERROR);
+            let board = "1 2 3 4 5\n".repeat(5);
+            let (code, message) = call(4, 1, format!("8,9\n\n{}", board).as_bytes());
+            assert_eq!(
+                (code, message.as_str()),
+                (AOC_UNSOLVABLE, "Day 4, part 1: no solution!")
+            );
+
+            let (mut first, mut last) = (0, 0);
+            aoc_days(&mut first, &mut last);
+            assert_eq!((first, last), (1, 17));
+        }
+    }
+
+    #[test]
+    fn test_header() {
+        let generated = include_str!(concat!(env!("OUT_DIR"), "/advent_of_rust_2021.h"));
+        assert!(
+            generated == include_str!("../include/advent_of_rust_2021.h"),
+            "include/advent_of_rust_2021.h is stale, copy it from {}",
+            env!("OUT_DIR")
+        );
+    }
+}
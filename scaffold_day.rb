@@ -2,41 +2,84 @@
 
 require 'fileutils'
 
-if ARGV.length != 1
-  puts 'Usage: ruby scaffold_day.rb <DAY_NUMBER>'
+if ARGV.length != 2
+  puts 'Usage: ruby scaffold_day.rb <DAY_NUMBER> <TITLE>'
   exit 1
 end
 
-day_nr = ARGV[0]
+day_nr, title = ARGV
 
 puts 'Creating input directory...'
 FileUtils.mkdir_p("inputs/day#{day_nr}")
 
-puts 'Creating scaffold Rust file...'
-File.open("src/bin/day#{day_nr}.rs", 'w') do |f|
+puts 'Creating scaffold Rust files...'
+File.open("src/days/day#{day_nr}.rs", 'w') do |f|
   f << <<~HEREDOC
-    fn main() {
-        println!("Day #{day_nr}, part 1: {}", part1());
-        println!("Day #{day_nr}, part 2: {}", part2());
+    use crate::days::{Metadata, Solution};
+    use crate::prelude::*;
+
+    pub fn read_input(input: &str) -> Vec<String> {
+        input.lines().map(|line| line.to_owned()).collect()
     }
 
-    fn part1() -> i32 {
-      unimplemented!();
+    pub fn part1(_input: &[String]) -> i32 {
+        unimplemented!();
     }
-    fn part2() -> i32 {
-      unimplemented!();
+    pub fn part2(_input: &[String]) -> i32 {
+        unimplemented!();
+    }
+
+    pub struct Day#{day_nr};
+
+    impl Solution for Day#{day_nr} {
+        const DAY: u8 = #{day_nr};
+        const METADATA: Metadata = Metadata {
+            title: #{title.dump},
+            part1: "",
+            part2: "",
+            params: &[],
+            visual: false,
+        };
+
+        type Input = Vec<String>;
+        type Part1 = i32;
+        type Part2 = i32;
+
+        fn parse(input: &str) -> Self::Input {
+            read_input(input)
+        }
+
+        fn part1(input: &Self::Input) -> Self::Part1 {
+            part1(input)
+        }
+
+        fn part2(input: &Self::Input) -> Self::Part2 {
+            part2(input)
+        }
     }
 
     #[cfg(test)]
     mod tests {
         use super::*;
-        use std::{fs::File, io::BufReader};
 
         #[test]
         fn test_solution() {
+            assert!(read_input("").is_empty());
         }
     }
   HEREDOC
 end
 
+File.open("src/bin/day#{day_nr}.rs", 'w') do |f|
+  f << <<~HEREDOC
+    use advent_of_rust_2021::days::day#{day_nr}::Day#{day_nr};
+    use advent_of_rust_2021::outcome;
+
+    fn main() {
+        outcome::main::<Day#{day_nr}>()
+    }
+  HEREDOC
+end
+
+puts "Register Day#{day_nr} in src/days/mod.rs (module, DAYS and with_day) and describe its parts."
 puts 'Done! Happy Advent!'
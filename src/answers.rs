@@ -0,0 +1,27 @@
+/// Answers accepted by the site for the inputs in `inputs/`, by day.
+const ANSWERS: [(u8, &str, &str); 17] = [
+    (1, "1226", "1252"),
+    (2, "1938402", "1947878632"),
+    (3, "2743844", "6677951"),
+    (4, "6592", "31755"),
+    (5, "7085", "20271"),
+    (6, "351188", "1595779846729"),
+    (7, "342534", "94004208"),
+    (8, "247", "933305"),
+    (9, "594", "858494"),
+    (10, "321237", "2360030859"),
+    (11, "1637", "242"),
+    (12, "5228", "131228"),
+    (13, "687", "98"),
+    (14, "2797", "2926813379532"),
+    (15, "604", "2907"),
+    (16, "938", "1495959086337"),
+    (17, "4095", "3773"),
+];
+
+pub fn answers(day: u8) -> Option<(&'static str, &'static str)> {
+    ANSWERS
+        .iter()
+        .find(|(answer_day, _, _)| *answer_day == day)
+        .map(|&(_, part1, part2)| (part1, part2))
+}
@@ -0,0 +1,168 @@
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+use std::process::{self, Command, Stdio};
+
+pub const SESSION_VAR: &str = "AOC_SESSION";
+pub const URL_VAR: &str = "AOC_URL";
+const DEFAULT_URL: &str = "https://adventofcode.com";
+
+/// Means of getting a page from the puzzle site, authenticated by the session cookie.
+pub trait Transport {
+    fn get(&self, url: &str, session: &str) -> Result<String, String>;
+}
+
+/// Downloads through `curl`, which handles TLS for us.
+pub struct Curl;
+
+/// The value as a quoted string of curl's config, which takes everything after a stray quote or
+/// line break for further options.
+fn quote(value: &str) -> Result<String, String> {
+    if value.chars().any(char::is_control) {
+        return Err("The session holds control characters".to_owned());
+    }
+    Ok(format!(
+        "\"{}\"",
+        value.replace('\\', "\\\\").replace('"', "\\\"")
+    ))
+}
+
+impl Transport for Curl {
+    fn get(&self, url: &str, session: &str) -> Result<String, String> {
+        let cookie = quote(&format!("session={}", session))?;
+        let mut curl = Command::new("curl")
+            // the config comes from stdin, so the session doesn't show up in the process list
+            .args(["--silent", "--show-error", "--fail", "--config", "-", url])
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()
+            .map_err(|err| format!("Cannot run curl: {}", err))?;
+
+        let config = format!(
+            "cookie = {}\nuser-agent = \"github.com/LesnyRumcajs/advent-of-rust-2021\"\n",
+            cookie
+        );
+        curl.stdin
+            .take()
+            .unwrap()
+            .write_all(config.as_bytes())
+            .map_err(|err| err.to_string())?;
+
+        let output = curl.wait_with_output().map_err(|err| err.to_string())?;
+        if !output.status.success() {
+            return Err(format!(
+                "Fetching {} failed: {}",
+                url,
+                String::from_utf8_lossy(&output.stderr).trim()
+            ));
+        }
+        String::from_utf8(output.stdout).map_err(|err| err.to_string())
+    }
+}
+
+pub struct Fetcher<T: Transport> {
+    pub transport: T,
+    pub url: String,
+    pub cache: PathBuf,
+}
+
+impl Fetcher<Curl> {
+    pub fn from_env() -> Self {
+        Self {
+            transport: Curl,
+            url: std::env::var(URL_VAR).unwrap_or_else(|_| DEFAULT_URL.to_owned()),
+            cache: PathBuf::from("inputs"),
+        }
+    }
+}
+
+impl<T: Transport> Fetcher<T> {
+    /// Path to the day's input, downloading it first unless it's already cached.
+    pub fn fetch(&self, day: u8, session: Option<&str>) -> Result<PathBuf, String> {
+        let dir = self.cache.join(format!("day{}", day));
+        let path = dir.join("input");
+        if path.exists() {
+            return Ok(path);
+        }
+
+        let session =
+            session.ok_or_else(|| format!("{} is needed to download the input", SESSION_VAR))?;
+        let input = self.transport.get(
+            &format!("{}/2021/day/{}/input", self.url.trim_end_matches('/'), day),
+            session,
+        )?;
+
+        // written aside and moved in whole, so a failed write is never taken for a cached input
+        fs::create_dir_all(&dir).map_err(|err| err.to_string())?;
+        let partial = dir.join(format!("input.{}.part", process::id()));
+        fs::write(&partial, input)
+            .and_then(|()| fs::rename(&partial, &path))
+            .map_err(|err| {
+                let _ = fs::remove_file(&partial);
+                err.to_string()
+            })?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::RefCell;
+    use std::env;
+
+    #[derive(Default)]
+    struct Fake {
+        requests: RefCell<Vec<(String, String)>>,
+    }
+
+    impl Transport for Fake {
+        fn get(&self, url: &str, session: &str) -> Result<String, String> {
+            self.requests
+                .borrow_mut()
+                .push((url.to_owned(), session.to_owned()));
+            Ok("1,2,3\n".to_owned())
+        }
+    }
+
+    #[test]
+    fn test_fetch() {
+        let cache = env::temp_dir().join(format!("aoc-fetch-{}", std::process::id()));
+        let fetcher = Fetcher {
+            transport: Fake::default(),
+            url: "http://localhost/".to_owned(),
+            cache: cache.clone(),
+        };
+
+        assert!(fetcher.fetch(6, None).is_err());
+        let path = fetcher.fetch(6, Some("cookie")).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "1,2,3\n");
+        assert_eq!(fetcher.fetch(6, Some("cookie")).unwrap(), path);
+        assert_eq!(
+            *fetcher.transport.requests.borrow(),
+            vec![(
+                "http://localhost/2021/day/6/input".to_owned(),
+                "cookie".to_owned()
+            )]
+        );
+
+        assert_eq!(
+            fs::read_dir(path.parent().unwrap()).unwrap().count(),
+            1,
+            "only the input is left"
+        );
+
+        fs::remove_dir_all(cache).unwrap();
+    }
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("session=abc").unwrap(), "\"session=abc\"");
+        assert_eq!(
+            quote("a\" -o \"/tmp/x\\").unwrap(),
+            "\"a\\\" -o \\\"/tmp/x\\\\\""
+        );
+        assert!(quote("abc\nurl = \"http://evil\"").is_err());
+    }
+}
@@ -0,0 +1,185 @@
+use std::fmt;
+use std::fs::{self, OpenOptions};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Feedback {
+    TooHigh,
+    TooLow,
+    Wrong,
+    Correct,
+}
+
+impl FromStr for Feedback {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "high" => Ok(Feedback::TooHigh),
+            "low" => Ok(Feedback::TooLow),
+            "wrong" => Ok(Feedback::Wrong),
+            "correct" => Ok(Feedback::Correct),
+            _ => Err(format!("Unknown feedback: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Feedback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Feedback::TooHigh => "high",
+            Feedback::TooLow => "low",
+            Feedback::Wrong => "wrong",
+            Feedback::Correct => "correct",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attempt {
+    pub answer: String,
+    pub feedback: Feedback,
+}
+
+/// Answers submitted for a single day and part, along with what the site said about them.
+#[derive(Debug, Default)]
+pub struct Ledger {
+    attempts: Vec<Attempt>,
+}
+
+pub fn ledger_path(day: u8, part: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/day{}/attempts_part{}", day, part))
+}
+
+impl Ledger {
+    pub fn load(day: u8, part: u8) -> io::Result<Self> {
+        let content = match fs::read_to_string(ledger_path(day, part)) {
+            Ok(content) => content,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(err),
+        };
+
+        let attempts = content
+            .lines()
+            .filter_map(|line| {
+                let (answer, feedback) = line.rsplit_once(' ')?;
+                Some(Attempt {
+                    answer: answer.to_owned(),
+                    feedback: feedback.parse().ok()?,
+                })
+            })
+            .collect();
+        Ok(Self { attempts })
+    }
+
+    pub fn record(day: u8, part: u8, attempt: &Attempt) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(ledger_path(day, part))?;
+        writeln!(file, "{} {}", attempt.answer, attempt.feedback)
+    }
+
+    /// Highest answer known to be too low and lowest answer known to be too high.
+    pub fn bounds(&self) -> (Option<i128>, Option<i128>) {
+        let numbers_with = |feedback| {
+            self.attempts
+                .iter()
+                .filter(move |attempt| attempt.feedback == feedback)
+                .filter_map(|attempt| attempt.answer.parse::<i128>().ok())
+        };
+        (
+            numbers_with(Feedback::TooLow).max(),
+            numbers_with(Feedback::TooHigh).min(),
+        )
+    }
+
+    /// Explains why the answer can't be right, if the ledger already knows it.
+    pub fn check(&self, answer: &str) -> Option<String> {
+        if let Some(attempt) = self
+            .attempts
+            .iter()
+            .find(|attempt| attempt.answer == answer)
+        {
+            return match attempt.feedback {
+                Feedback::Correct => None,
+                Feedback::Wrong => Some(format!("{} was already rejected", answer)),
+                feedback => Some(format!(
+                    "{} was already rejected as too {}",
+                    answer, feedback
+                )),
+            };
+        }
+
+        if let Some(correct) = self
+            .attempts
+            .iter()
+            .find(|attempt| attempt.feedback == Feedback::Correct)
+        {
+            return Some(format!(
+                "{} differs from the accepted answer {}",
+                answer, correct.answer
+            ));
+        }
+
+        let number = answer.parse::<i128>().ok()?;
+        match self.bounds() {
+            (Some(low), _) if number <= low => {
+                Some(format!("{} is not above {}, which is too low", answer, low))
+            }
+            (_, Some(high)) if number >= high => Some(format!(
+                "{} is not below {}, which is too high",
+                answer, high
+            )),
+            _ => None,
+        }
+    }
+}
+
+/// Warns about an answer the ledger already rules out, before it gets printed.
+pub fn warn_if_known_wrong(day: u8, part: u8, answer: &str) {
+    match Ledger::load(day, part) {
+        Ok(ledger) => {
+            if let Some(warning) = ledger.check(answer) {
+                eprintln!("Warning: day {}, part {}: {}", day, part, warning);
+            }
+        }
+        Err(err) => eprintln!("Cannot read {}: {}", ledger_path(day, part).display(), err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(answer: &str, feedback: Feedback) -> Attempt {
+        Attempt {
+            answer: answer.to_owned(),
+            feedback,
+        }
+    }
+
+    #[test]
+    fn test_check() {
+        let mut ledger = Ledger {
+            attempts: vec![
+                attempt("100", Feedback::TooLow),
+                attempt("500", Feedback::TooHigh),
+                attempt("300", Feedback::Wrong),
+                attempt("200", Feedback::TooLow),
+            ],
+        };
+        assert_eq!(ledger.bounds(), (Some(200), Some(500)));
+        assert_eq!(ledger.check("250"), None);
+        assert!(ledger.check("300").is_some());
+        assert!(ledger.check("150").is_some());
+        assert!(ledger.check("200").is_some());
+        assert!(ledger.check("600").is_some());
+
+        ledger.attempts.push(attempt("250", Feedback::Correct));
+        assert_eq!(ledger.check("250"), None);
+        assert!(ledger.check("260").is_some());
+    }
+}
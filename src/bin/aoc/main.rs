@@ -0,0 +1,403 @@
+use advent_of_rust_2021::answers::answers;
+use advent_of_rust_2021::days::day2::{self, Day2, Model};
+use advent_of_rust_2021::days::{metadata, with_day, Metadata, Solution, Visitor, DAYS};
+use advent_of_rust_2021::input_read::input_path;
+use advent_of_rust_2021::outcome::{self, Failure};
+use advent_of_rust_2021::terminal;
+use advent_of_rust_2021::trace::{self, Level};
+use fetch::{Fetcher, SESSION_VAR};
+use ledger::{warn_if_known_wrong, Attempt, Ledger};
+use repl::Repl;
+use std::fmt::Display;
+use std::io::Write;
+use std::path::Path;
+use std::time::Duration;
+use std::{env, fs, io};
+use watch::Watch;
+
+mod fetch;
+mod ledger;
+mod readme;
+mod repl;
+mod solve;
+mod watch;
+
+const USAGE: &str = "Usage:
+    aoc run <DAY> [--input <PATH>] [--animate] [--delay <MS>] [--explain[=info|debug|trace]] [--verify]
+            [--with <PARAM>]... [--show]
+    aoc watch <DAY> [--examples] [--interval <MS>]
+    aoc attempt <DAY> <PART> <ANSWER> <high|low|wrong|correct>
+    aoc fetch <DAY>
+    aoc readme [PATH]
+    aoc repl <DAY> [--input <PATH>]
+    aoc stream <DAY> < INPUT
+    aoc trajectory <plain|aimed> <csv|svg> [--input <PATH>]
+    aoc plan <plain|aimed> <POSITION> <DEPTH>
+    aoc list";
+
+struct Run {
+    input: String,
+    animation: Option<Duration>,
+    show: bool,
+    verify: bool,
+    params: Vec<String>,
+}
+
+impl Visitor for Run {
+    type Output = Result<(), Failure>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        let input = outcome::parse::<S>(&self.input)?;
+        if self.show {
+            match outcome::guard::<S, _>(|| S::show(&input))? {
+                Some(shown) => print(shown.trim_end())?,
+                None => eprintln!("Day {} has nothing to show", S::DAY),
+            }
+        }
+        if let Some(delay) = self.animation {
+            match outcome::guard::<S, _>(|| S::frames(&input))? {
+                Some(frames) => animate::<S>(frames, delay)?,
+                None => eprintln!("Day {} has nothing to animate", S::DAY),
+            }
+        }
+
+        let params: Vec<&str> = self.params.iter().map(String::as_str).collect();
+        if !params.is_empty() && S::METADATA.params.is_empty() {
+            return Err(Failure::Usage(format!(
+                "Day {} takes no parameters",
+                S::DAY
+            )));
+        }
+        let mut mismatches = Vec::new();
+        for part in [1, 2] {
+            // parts that take no parameters are solved as usual
+            if !params.is_empty() && S::METADATA.params.iter().any(|&(with, _)| with == part) {
+                let answer = outcome::part_with::<S>(&input, part, &params)?;
+                print(format_args!("Day {}, part {}: {}", S::DAY, part, answer))?;
+                continue;
+            }
+
+            let answer = outcome::part::<S>(&input, part)?;
+            warn_if_known_wrong(S::DAY, part, &answer);
+            print(format_args!("Day {}, part {}: {}", S::DAY, part, answer))?;
+
+            let accepted = answers(S::DAY).map(|(part1, part2)| [part1, part2][part as usize - 1]);
+            match accepted {
+                Some(accepted) if self.verify && accepted != answer => mismatches.push(format!(
+                    "Day {}, part {}: {} differs from the accepted {}",
+                    S::DAY,
+                    part,
+                    answer,
+                    accepted
+                )),
+                None if self.verify => {
+                    mismatches.push(format!("Day {} has no accepted answers", S::DAY))
+                }
+                _ => {}
+            }
+        }
+
+        if let Some(drawing) = outcome::guard::<S, _>(|| S::drawing(&input))? {
+            print(drawing)?;
+        }
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(Failure::Mismatch(mismatches.join("\n")))
+        }
+    }
+}
+
+/// Solves both parts reading the standard input once, a line at a time.
+struct Stream;
+
+impl Visitor for Stream {
+    type Output = Result<(), Failure>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        let (part1, part2) = outcome::guard::<S, _>(|| S::stream(&mut io::stdin().lock()))?
+            .ok_or_else(|| Failure::Usage(format!("Day {} can't be streamed", S::DAY)))??;
+        print(format_args!("Day {}, part 1: {}", S::DAY, part1))?;
+        print(format_args!("Day {}, part 2: {}", S::DAY, part2))
+    }
+}
+
+/// Plays the frames of a day, failing as unsolvable if one of them panics.
+fn animate<S: Solution>(
+    frames: impl Iterator<Item = String>,
+    delay: Duration,
+) -> Result<(), Failure> {
+    let mut out = io::stdout().lock();
+    match outcome::guard::<S, _>(|| terminal::animate(&mut out, frames, delay)) {
+        Ok(played) => played.map_err(outcome::write_failure),
+        Err(failure) => {
+            // the cursor was hidden for the animation
+            let _ = write!(out, "{}", terminal::SHOW_CURSOR);
+            Err(failure)
+        }
+    }
+}
+
+/// Writes a line to the standard output, which may have been closed, e.g. by `head`.
+fn print(line: impl Display) -> Result<(), Failure> {
+    writeln!(io::stdout().lock(), "{}", line).map_err(outcome::write_failure)
+}
+
+fn usage() -> Failure {
+    Failure::Usage(USAGE.to_owned())
+}
+
+fn parse_day(arg: Option<&String>) -> Result<u8, Failure> {
+    arg.and_then(|day| day.parse().ok()).ok_or_else(usage)
+}
+
+fn not_implemented(day: u8) -> Failure {
+    Failure::Usage(format!("Day {} is not implemented", day))
+}
+
+fn read_input(path: &Path) -> Result<String, Failure> {
+    fs::read_to_string(path)
+        .map_err(|err| Failure::Io(format!("Cannot read {}: {}", path.display(), err)))
+}
+
+fn run(args: &[String]) -> Result<(), Failure> {
+    let day = parse_day(args.first())?;
+    let mut path = input_path(day);
+    let mut animate = false;
+    let mut show = false;
+    let mut verify = false;
+    let mut params = Vec::new();
+    let mut delay = Duration::from_millis(50);
+
+    let mut args = args.iter().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--input" => path = args.next().ok_or_else(usage)?.into(),
+            "--animate" => animate = true,
+            "--explain" => trace::enable(day, Level::Debug),
+            "--show" => show = true,
+            "--verify" => verify = true,
+            "--with" => params.push(args.next().ok_or_else(usage)?.to_owned()),
+            "--delay" => {
+                let millis = args
+                    .next()
+                    .and_then(|ms| ms.parse().ok())
+                    .ok_or_else(usage)?;
+                delay = Duration::from_millis(millis);
+            }
+            _ => match arg.strip_prefix("--explain=") {
+                Some(level) => trace::enable(day, level.parse()?),
+                None => return Err(usage()),
+            },
+        }
+    }
+
+    let run = Run {
+        input: read_input(&path)?,
+        animation: animate.then_some(delay),
+        show,
+        verify,
+        params,
+    };
+    with_day(day, run).ok_or_else(|| not_implemented(day))?
+}
+
+fn watch(args: &[String]) -> Result<(), Failure> {
+    let mut watch = Watch {
+        day: parse_day(args.first())?,
+        examples: false,
+        interval: Duration::from_millis(500),
+    };
+
+    let mut args = args.iter().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--examples" => watch.examples = true,
+            "--interval" => {
+                let millis = args
+                    .next()
+                    .and_then(|ms| ms.parse().ok())
+                    .ok_or_else(usage)?;
+                watch.interval = Duration::from_millis(millis);
+            }
+            _ => return Err(usage()),
+        }
+    }
+
+    watch.run()
+}
+
+fn attempt(args: &[String]) -> Result<(), Failure> {
+    let (day, part, answer, feedback) = match args {
+        [day, part, answer, feedback] => (day, part, answer, feedback),
+        _ => return Err(usage()),
+    };
+    let day = parse_day(Some(day))?;
+    let part = match part.as_str() {
+        "1" => 1,
+        "2" => 2,
+        _ => return Err(usage()),
+    };
+    let attempt = Attempt {
+        answer: answer.to_owned(),
+        feedback: feedback.parse()?,
+    };
+
+    Ledger::record(day, part, &attempt).map_err(|err| Failure::Io(err.to_string()))?;
+    let ledger = Ledger::load(day, part).map_err(|err| Failure::Io(err.to_string()))?;
+    match ledger.bounds() {
+        (None, None) => {}
+        (low, high) => print(format_args!(
+            "Day {}, part {}: answer between {} and {}",
+            day,
+            part,
+            low.map_or("?".to_owned(), |low| low.to_string()),
+            high.map_or("?".to_owned(), |high| high.to_string())
+        ))?,
+    }
+    Ok(())
+}
+
+fn fetch(args: &[String]) -> Result<(), Failure> {
+    let day = match args {
+        [day] => parse_day(Some(day))?,
+        _ => return Err(usage()),
+    };
+
+    let session = env::var(SESSION_VAR).ok();
+    let path = Fetcher::from_env().fetch(day, session.as_deref())?;
+    print(path.display())
+}
+
+fn update_readme(args: &[String]) -> Result<(), Failure> {
+    let path = match args {
+        [] => "README.md",
+        [path] => path.as_str(),
+        _ => return Err(usage()),
+    };
+
+    let progress: Vec<_> = (1..=25).map(readme::progress).collect();
+    let table = readme::star_table(&progress);
+    let content = read_input(Path::new(path))?;
+    fs::write(path, readme::replace_table(&content, &table)?)
+        .map_err(|err| Failure::Io(format!("Cannot write {}: {}", path, err)))?;
+    print(table.trim_end_matches('\n'))
+}
+
+fn repl(args: &[String]) -> Result<(), Failure> {
+    let day = parse_day(args.first())?;
+    let path = match &args[1..] {
+        [] => input_path(day),
+        [flag, path] if flag == "--input" => path.into(),
+        _ => return Err(usage()),
+    };
+
+    let input = read_input(&path)?;
+    with_day(day, Repl { input }).ok_or_else(|| not_implemented(day))?
+}
+
+fn stream(args: &[String]) -> Result<(), Failure> {
+    let day = match args {
+        [day] => parse_day(Some(day))?,
+        _ => return Err(usage()),
+    };
+
+    with_day(day, Stream).ok_or_else(|| not_implemented(day))?
+}
+
+/// Exports the course of the submarine of day 2, steered either way.
+fn trajectory(args: &[String]) -> Result<(), Failure> {
+    let (model, format, path) = match args {
+        [model, format] => (model, format, input_path(Day2::DAY)),
+        [model, format, flag, path] if flag == "--input" => (model, format, path.into()),
+        _ => return Err(usage()),
+    };
+    let model: Model = model.parse().map_err(Failure::Usage)?;
+    let export = match format.as_str() {
+        "csv" => day2::to_csv,
+        "svg" => day2::depth_profile_svg,
+        _ => return Err(usage()),
+    };
+
+    let input = outcome::parse::<Day2>(&read_input(&path)?)?;
+    let states: Vec<_> = day2::trajectory(&day2::unroll(&input), model).collect();
+    print(export(&states).trim_end_matches('\n'))
+}
+
+/// Prints the shortest day 2 input taking the submarine to the target.
+fn plan(args: &[String]) -> Result<(), Failure> {
+    let (model, pos, depth) = match args {
+        [model, pos, depth] => (model, pos, depth),
+        _ => return Err(usage()),
+    };
+    let model: Model = model.parse().map_err(Failure::Usage)?;
+    let target = (
+        pos.parse().map_err(|_| usage())?,
+        depth.parse().map_err(|_| usage())?,
+    );
+
+    let plan = day2::plan(target, model).ok_or_else(|| {
+        Failure::Unsolvable(format!(
+            "Day 2: the submarine can't get to {}, {}",
+            target.0, target.1
+        ))
+    })?;
+    for instr in plan {
+        print(instr)?;
+    }
+    Ok(())
+}
+
+fn describe(day: u8, metadata: &Metadata) -> String {
+    let part = |part, description| {
+        let params = metadata
+            .params
+            .iter()
+            .filter(|(params_part, _)| *params_part == part)
+            .map(|(_, param)| format!(" [{}]", param))
+            .collect::<String>();
+        format!("\n    part {}{}: {}", part, params, description)
+    };
+
+    format!(
+        "Day {}: {}{}{}{}",
+        day,
+        metadata.title,
+        if metadata.visual { " (visual)" } else { "" },
+        part(1, metadata.part1),
+        part(2, metadata.part2)
+    )
+}
+
+fn list(args: &[String]) -> Result<(), Failure> {
+    if !args.is_empty() {
+        return Err(usage());
+    }
+
+    for day in DAYS {
+        if let Some(metadata) = metadata(day) {
+            print(describe(day, &metadata))?;
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let result = match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..]),
+        Some("watch") => watch(&args[1..]),
+        Some("attempt") => attempt(&args[1..]),
+        Some("fetch") => fetch(&args[1..]),
+        Some("readme") => update_readme(&args[1..]),
+        Some("repl") => repl(&args[1..]),
+        Some("stream") => stream(&args[1..]),
+        Some("trajectory") => trajectory(&args[1..]),
+        Some("plan") => plan(&args[1..]),
+        Some("list") => list(&args[1..]),
+        _ => Err(usage()),
+    };
+
+    outcome::exit(result)
+}
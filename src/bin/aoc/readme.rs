@@ -0,0 +1,108 @@
+use crate::solve::Solve;
+use advent_of_rust_2021::answers::answers;
+use advent_of_rust_2021::days::{metadata, with_day};
+use advent_of_rust_2021::input_read::input_path;
+use std::fs;
+use std::time::Duration;
+
+pub const TABLE_START: &str = "<!-- stars:start -->";
+pub const TABLE_END: &str = "<!-- stars:end -->";
+
+/// Stars earned on a single day, i.e. parts matching the accepted answers.
+pub struct Progress {
+    pub day: u8,
+    pub title: Option<&'static str>,
+    pub stars: usize,
+    pub time: Option<Duration>,
+}
+
+/// Solves the day on its input and checks the answers against the accepted ones.
+pub fn progress(day: u8) -> Progress {
+    let report = fs::read_to_string(input_path(day))
+        .ok()
+        .and_then(|input| with_day(day, Solve { input: &input }))
+        .and_then(Result::ok);
+
+    let title = metadata(day).map(|metadata| metadata.title);
+    match (report, answers(day)) {
+        (Some(report), Some((part1, part2))) => Progress {
+            day,
+            title,
+            stars: (report.part1 == part1) as usize + (report.part2 == part2) as usize,
+            time: Some(report.parse_time + report.part1_time + report.part2_time),
+        },
+        (report, _) => Progress {
+            day,
+            title,
+            stars: 0,
+            time: report.map(|report| report.parse_time + report.part1_time + report.part2_time),
+        },
+    }
+}
+
+/// Days laid out in columns of five, as on the calendar.
+pub fn star_table(progress: &[Progress]) -> String {
+    let mut table = "| 1-5 | 6-10 | 11-15 | 16-20 | 21-25 |\n|---|---|---|---|---|\n".to_owned();
+    for row in 0..5 {
+        for column in 0..5 {
+            table.push('|');
+            let day = (column * 5 + row + 1) as u8;
+            if let Some(progress) = progress.iter().find(|progress| progress.day == day) {
+                if let Some(title) = progress.title {
+                    table += &format!("{} ", title);
+                }
+                table += &"⭐".repeat(progress.stars);
+                if let (true, Some(time)) = (progress.stars > 0, progress.time) {
+                    table += &format!(" {:.1?}", time);
+                }
+            }
+        }
+        table += "|\n";
+    }
+    table
+}
+
+pub fn replace_table(readme: &str, table: &str) -> Result<String, String> {
+    let markers_missing = || format!("README lacks the {} and {} markers", TABLE_START, TABLE_END);
+    let start = readme.find(TABLE_START).ok_or_else(markers_missing)? + TABLE_START.len();
+    let end = readme[start..]
+        .find(TABLE_END)
+        .ok_or_else(markers_missing)?
+        + start;
+
+    Ok(format!("{}\n{}{}", &readme[..start], table, &readme[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_replace_table() {
+        let progress = [
+            Progress {
+                day: 1,
+                title: Some("Sonar Sweep"),
+                stars: 2,
+                time: Some(Duration::from_millis(2)),
+            },
+            Progress {
+                day: 7,
+                title: None,
+                stars: 1,
+                time: None,
+            },
+        ];
+        let readme = format!("# AoC\n{}\nstale\n{}\nfooter\n", TABLE_START, TABLE_END);
+
+        assert_eq!(
+            replace_table(&readme, &star_table(&progress)).unwrap(),
+            format!(
+                "# AoC\n{}\n| 1-5 | 6-10 | 11-15 | 16-20 | 21-25 |\n|---|---|---|---|---|\n\
+                 |Sonar Sweep ⭐⭐ 2.0ms|||||\n||⭐||||\n||||||\n||||||\n||||||\n{}\nfooter\n",
+                TABLE_START, TABLE_END
+            )
+        );
+        assert!(replace_table("# AoC\n", "").is_err());
+    }
+}
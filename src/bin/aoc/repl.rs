@@ -0,0 +1,77 @@
+use crate::print;
+use crate::solve::timed;
+use advent_of_rust_2021::days::{Solution, Visitor};
+use advent_of_rust_2021::outcome::{self, Failure};
+use std::io::{self, BufRead, Write};
+
+const HELP: &str = "Commands:
+    part1 [PARAMS...]  solve part 1, optionally with other parameters than the puzzle's
+    part2 [PARAMS...]  solve part 2, optionally with other parameters than the puzzle's
+    show               print the parsed input
+    help               print this help
+    quit               leave";
+
+/// Keeps the parsed input around and solves it over and over, as commanded.
+pub struct Repl {
+    pub input: String,
+}
+
+fn execute<S: Solution>(
+    input: &S::Input,
+    command: &str,
+    params: &[&str],
+) -> Result<String, String> {
+    match (command, params) {
+        ("part1", []) => Ok(S::part1(input).to_string()),
+        ("part2", []) => Ok(S::part2(input).to_string()),
+        ("part1", params) => S::part_with(input, 1, params),
+        ("part2", params) => S::part_with(input, 2, params),
+        ("show", []) => S::show(input).ok_or_else(|| format!("Day {} has nothing to show", S::DAY)),
+        _ => Err(format!("Unknown command: {}, try help", command)),
+    }
+}
+
+impl Visitor for Repl {
+    type Output = Result<(), Failure>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        let (input, parse_time) = timed(|| outcome::parse::<S>(&self.input));
+        let input = input?;
+        print(format_args!(
+            "Day {} parsed in {:?}, try help",
+            S::DAY,
+            parse_time
+        ))?;
+        session::<S>(&input).map_err(|err| Failure::Io(err.to_string()))
+    }
+}
+
+/// Reads and executes commands until the end of the input or quit.
+fn session<S: Solution>(input: &S::Input) -> io::Result<()> {
+    let mut lines = io::stdin().lock().lines();
+    let mut out = io::stdout();
+    loop {
+        write!(out, "day{}> ", S::DAY)?;
+        out.flush()?;
+        let line = match lines.next() {
+            Some(line) => line?,
+            None => return Ok(()),
+        };
+
+        let words: Vec<&str> = line.split_whitespace().collect();
+        match words.as_slice() {
+            [] => {}
+            ["quit"] | ["exit"] => return Ok(()),
+            ["help"] => writeln!(out, "{}", HELP)?,
+            [command, params @ ..] => {
+                let (result, time) =
+                    timed(|| outcome::catch(|| execute::<S>(input, command, params)));
+                match result {
+                    Ok(Ok(output)) => writeln!(out, "{}\n({:?})", output.trim_end(), time)?,
+                    Ok(Err(err)) => eprintln!("{}", err),
+                    Err(err) => eprintln!("{} failed: {}", command, err),
+                }
+            }
+        }
+    }
+}
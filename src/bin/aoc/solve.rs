@@ -0,0 +1,60 @@
+use advent_of_rust_2021::days::{Solution, Visitor};
+use advent_of_rust_2021::outcome::{self, Failure};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// Parses the input and solves both parts, timing every step.
+pub struct Solve<'a> {
+    pub input: &'a str,
+}
+
+pub struct Report {
+    pub day: u8,
+    pub part1: String,
+    pub part2: String,
+    pub parse_time: Duration,
+    pub part1_time: Duration,
+    pub part2_time: Duration,
+}
+
+pub fn timed<T>(f: impl FnOnce() -> T) -> (T, Duration) {
+    let start = Instant::now();
+    let result = f();
+    (result, start.elapsed())
+}
+
+impl Visitor for Solve<'_> {
+    type Output = Result<Report, Failure>;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        let (input, parse_time) = timed(|| outcome::parse::<S>(self.input));
+        let input = input?;
+        let (part1, part1_time) = timed(|| outcome::part::<S>(&input, 1));
+        let (part2, part2_time) = timed(|| outcome::part::<S>(&input, 2));
+
+        Ok(Report {
+            day: S::DAY,
+            part1: part1?,
+            part2: part2?,
+            parse_time,
+            part1_time,
+            part2_time,
+        })
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Day {}, parsing: {:?}", self.day, self.parse_time)?;
+        writeln!(
+            f,
+            "Day {}, part 1: {} ({:?})",
+            self.day, self.part1, self.part1_time
+        )?;
+        write!(
+            f,
+            "Day {}, part 2: {} ({:?})",
+            self.day, self.part2, self.part2_time
+        )
+    }
+}
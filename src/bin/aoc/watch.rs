@@ -0,0 +1,128 @@
+use crate::ledger::warn_if_known_wrong;
+use crate::print;
+use crate::solve::Solve;
+use advent_of_rust_2021::days::with_day;
+use advent_of_rust_2021::input_read::{example_paths, input_path};
+use advent_of_rust_2021::outcome::Failure;
+#[cfg(unix)]
+use std::os::unix::process::CommandExt;
+use std::path::PathBuf;
+#[cfg(not(unix))]
+use std::process;
+use std::process::Command;
+use std::time::{Duration, SystemTime};
+use std::{env, fs, thread};
+
+pub struct Watch {
+    pub day: u8,
+    pub examples: bool,
+    pub interval: Duration,
+}
+
+type Snapshot = Vec<(PathBuf, Option<SystemTime>)>;
+
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    paths
+        .iter()
+        .map(|path| {
+            let modified = fs::metadata(path).and_then(|meta| meta.modified()).ok();
+            (path.to_owned(), modified)
+        })
+        .collect()
+}
+
+fn source_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("src/days/day{}.rs", day))
+}
+
+/// Builds the runner anew and hands over to it, as the solvers are compiled in.
+fn rebuild() -> Result<(), String> {
+    // resolved upfront, as the build replaces the running executable
+    let exe = env::current_exe().map_err(|err| err.to_string())?;
+    let mut cargo = Command::new(env::var("CARGO").unwrap_or_else(|_| "cargo".to_owned()));
+    cargo.args(["build", "--bin", "aoc"]);
+    if !cfg!(debug_assertions) {
+        cargo.arg("--release");
+    }
+    let status = cargo.status().map_err(|err| err.to_string())?;
+    if !status.success() {
+        eprintln!("Build failed, waiting for further changes...");
+        return Ok(());
+    }
+
+    let mut runner = Command::new(exe);
+    runner.args(env::args().skip(1));
+    hand_over(runner)
+}
+
+/// Replaces this process with the rebuilt runner, so rebuilds don't pile up waiting processes.
+#[cfg(unix)]
+fn hand_over(mut runner: Command) -> Result<(), String> {
+    // only returns if the runner couldn't be started
+    Err(runner.exec().to_string())
+}
+
+/// Without exec, the rebuilt runner takes over as a child, and this one exits along with it.
+#[cfg(not(unix))]
+fn hand_over(mut runner: Command) -> Result<(), String> {
+    let status = runner.status().map_err(|err| err.to_string())?;
+    process::exit(status.code().unwrap_or(1));
+}
+
+impl Watch {
+    fn inputs(&self) -> Vec<PathBuf> {
+        let mut inputs = vec![input_path(self.day)];
+        if self.examples {
+            inputs.extend(example_paths(self.day));
+        }
+        inputs
+    }
+
+    fn solve_all(&self, inputs: &[PathBuf]) -> Result<(), Failure> {
+        for path in inputs {
+            print(format_args!("== {} ==", path.display()))?;
+            match fs::read_to_string(path) {
+                // a half-written input shouldn't bring the whole watch down
+                Ok(input) => match with_day(self.day, Solve { input: &input }) {
+                    Some(Ok(report)) => {
+                        warn_if_known_wrong(self.day, 1, &report.part1);
+                        warn_if_known_wrong(self.day, 2, &report.part2);
+                        print(report)?;
+                    }
+                    Some(Err(failure)) => eprintln!("{}", failure),
+                    None => eprintln!("Day {} is not implemented", self.day),
+                },
+                Err(err) => eprintln!("Cannot read {}: {}", path.display(), err),
+            }
+        }
+        Ok(())
+    }
+
+    /// Polls the inputs and the day's source, solving again after every change.
+    pub fn run(&self) -> Result<(), Failure> {
+        let source = vec![source_path(self.day)];
+        let mut source_snapshot = snapshot(&source);
+        let mut input_snapshot = Snapshot::new();
+
+        loop {
+            let current = snapshot(&source);
+            if current != source_snapshot {
+                print(format_args!(
+                    "== {} changed, rebuilding ==",
+                    source[0].display()
+                ))?;
+                rebuild()?;
+                source_snapshot = current;
+            }
+
+            let inputs = self.inputs();
+            let current = snapshot(&inputs);
+            if current != input_snapshot {
+                self.solve_all(&inputs)?;
+                input_snapshot = current;
+            }
+
+            thread::sleep(self.interval);
+        }
+    }
+}
@@ -0,0 +1,322 @@
+use crate::days::{single_param, Metadata, Solution};
+#[cfg(feature = "std")]
+use crate::outcome::{self, Failure};
+use crate::overflow;
+use crate::prelude::*;
+use alloc::collections::BTreeMap;
+use core::fmt;
+use itertools::Itertools;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+/// A line of the sweep that isn't a depth.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DepthError {
+    /// A blank line followed by more depths; blank lines at the end are fine.
+    Blank {
+        line: usize,
+    },
+    Malformed {
+        line: usize,
+        text: String,
+    },
+}
+
+impl fmt::Display for DepthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DepthError::Blank { line } => write!(f, "line {}: blank line between depths", line),
+            DepthError::Malformed { line, text } => {
+                write!(f, "line {}: {:?} is not a depth", line, text)
+            }
+        }
+    }
+}
+
+/// Depths of the sweep, or every line that isn't one.
+pub fn read_numbers(input: &str) -> Result<Vec<i64>, Vec<DepthError>> {
+    let mut numbers = Vec::new();
+    let mut errors = Vec::new();
+    let mut blank = None;
+    for (i, text) in input.lines().enumerate() {
+        match parse_depth(i + 1, text, &mut blank) {
+            Ok(Some(depth)) => numbers.push(depth),
+            Ok(None) => {}
+            Err(err) => errors.push(err),
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(numbers)
+    } else {
+        Err(errors)
+    }
+}
+
+/// Parses a line, remembering the first of the blank lines since the last depth in `blank`, so
+/// they can be told from the trailing ones once another depth shows up.
+fn parse_depth(
+    line: usize,
+    text: &str,
+    blank: &mut Option<usize>,
+) -> Result<Option<i64>, DepthError> {
+    let text = text.trim();
+    if text.is_empty() {
+        blank.get_or_insert(line);
+        return Ok(None);
+    }
+
+    let depth = text.parse().map_err(|_| DepthError::Malformed {
+        line,
+        text: text.to_owned(),
+    })?;
+    match blank.take() {
+        Some(line) => Err(DepthError::Blank { line }),
+        None => Ok(Some(depth)),
+    }
+}
+
+/// Counts the sums of `window` neighbouring depths larger than the previous one. Neighbouring
+/// windows share all but the entering and leaving depth, so only those two are compared. Windows
+/// hold at least one depth.
+pub fn count_window_increases(numbers: &[i64], window: usize) -> i32 {
+    assert!(window > 0, "a window holds at least one depth");
+    numbers
+        .iter()
+        .zip(numbers.iter().skip(window))
+        .fold(0, |sum, (leaving, entering)| {
+            if entering > leaving {
+                overflow::add(Day1::DAY, "count of increases", sum, 1)
+            } else {
+                sum
+            }
+        })
+}
+
+pub fn part1(numbers: &[i64]) -> i32 {
+    count_window_increases(numbers, 1)
+}
+
+pub fn part2(numbers: &[i64]) -> i32 {
+    count_window_increases(numbers, 3)
+}
+
+/// What the sweep went through, besides the increases. Positions are indices into the depths.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Statistics {
+    /// First and last position of the longest strictly increasing run, the earliest one on ties.
+    pub longest_run: Option<(usize, usize)>,
+    /// Position of the depth reached by the largest change, either way, and the change itself.
+    pub largest_jump: Option<(usize, i64)>,
+    pub decreases: Vec<usize>,
+    /// Occurrences of every change between neighbouring depths.
+    pub deltas: BTreeMap<i64, usize>,
+}
+
+pub fn statistics(numbers: &[i64]) -> Statistics {
+    let mut statistics = Statistics {
+        longest_run: (!numbers.is_empty()).then_some((0, 0)),
+        ..Statistics::default()
+    };
+
+    let mut run_start = 0;
+    for (i, (previous, depth)) in numbers.iter().tuple_windows().enumerate() {
+        let position = i + 1;
+        let delta = overflow::sub(Day1::DAY, "depth delta", *depth, *previous);
+        *statistics.deltas.entry(delta).or_insert(0) += 1;
+
+        if delta > 0 {
+            if let Some((start, end)) = statistics.longest_run {
+                if position - run_start > end - start {
+                    statistics.longest_run = Some((run_start, position));
+                }
+            }
+        } else {
+            run_start = position;
+        }
+        if delta < 0 {
+            statistics.decreases.push(position);
+        }
+        if statistics
+            .largest_jump
+            .is_none_or(|(_, largest)| delta.unsigned_abs() > largest.unsigned_abs())
+        {
+            statistics.largest_jump = Some((position, delta));
+        }
+    }
+    statistics
+}
+
+impl fmt::Display for Statistics {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some((start, end)) = self.longest_run {
+            writeln!(
+                f,
+                "Longest increasing run: {} depths, from #{} to #{}",
+                end - start + 1,
+                start,
+                end
+            )?;
+        }
+        if let Some((position, delta)) = self.largest_jump {
+            writeln!(f, "Largest jump: {:+} to #{}", delta, position)?;
+        }
+        writeln!(
+            f,
+            "Decreases: {}, at {}",
+            self.decreases.len(),
+            self.decreases
+                .iter()
+                .map(|position| format!("#{}", position))
+                .join(", ")
+        )?;
+
+        writeln!(f, "Deltas:")?;
+        let most = self.deltas.values().copied().max().unwrap_or(0);
+        for (delta, count) in &self.deltas {
+            // bars of at most 50 marks, so that the histogram fits the terminal
+            let bar = "#".repeat((count * 50).div_ceil(most));
+            writeln!(f, "{:>+6} {:>5} {}", delta, count, bar)?;
+        }
+        Ok(())
+    }
+}
+
+/// Both parts in a single pass, keeping just the last three depths around.
+#[cfg(feature = "std")]
+pub fn stream<R: BufRead>(reader: R) -> Result<(i32, i32), Failure> {
+    let mut last = [0; 3];
+    let mut increases = 0;
+    let mut window_increases = 0;
+
+    let mut blank = None;
+    let mut i = 0;
+    for line in outcome::lines::<Day1>(reader) {
+        let (line, text) = line?;
+        let depth = match parse_depth(line, &text, &mut blank) {
+            Ok(Some(depth)) => depth,
+            Ok(None) => continue,
+            Err(err) => return Err(outcome::parse_failure::<Day1>(err)),
+        };
+
+        if i >= 1 && depth > last[(i - 1) % 3] {
+            increases = overflow::add(Day1::DAY, "count of increases", increases, 1);
+        }
+        // neighbouring windows share all but the entering and leaving depth
+        if i >= 3 && depth > last[i % 3] {
+            window_increases = overflow::add(Day1::DAY, "count of increases", window_increases, 1);
+        }
+        last[i % 3] = depth;
+        i += 1;
+    }
+
+    Ok((increases, window_increases))
+}
+
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+    const METADATA: Metadata = Metadata {
+        title: "Sonar Sweep",
+        part1: "count depth increases",
+        part2: "count increases of three-measurement window sums",
+        params: &[(1, "WINDOW"), (2, "WINDOW")],
+        visual: false,
+    };
+
+    type Input = Vec<i64>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Input {
+        read_numbers(input).unwrap_or_else(|errors| panic!("{}", errors.iter().join("\n    ")))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn show(input: &Self::Input) -> Option<String> {
+        Some(statistics(input).to_string())
+    }
+
+    fn part_with(input: &Self::Input, _part: u8, params: &[&str]) -> Result<String, String> {
+        match single_param(params)? {
+            0 => Err("A window holds at least one depth".to_owned()),
+            window => Ok(count_window_increases(input, window).to_string()),
+        }
+    }
+
+    #[cfg(feature = "std")]
+    fn stream(reader: &mut dyn BufRead) -> Option<Result<(String, String), Failure>> {
+        Some(stream(reader).map(|(part1, part2)| (part1.to_string(), part2.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let numbers = read_numbers(&fs::read_to_string("inputs/day1/input").unwrap()).unwrap();
+        assert_eq!(part1(&numbers), 1226);
+        assert_eq!(part2(&numbers), 1252);
+        assert_eq!(count_window_increases(&numbers, 2), 1203);
+        assert!(Day1::part_with(&numbers, 1, &["0"]).is_err());
+    }
+
+    #[test]
+    fn test_read_numbers() {
+        assert_eq!(read_numbers("199\n200\n\n\n"), Ok(vec![199, 200]));
+        assert_eq!(read_numbers("3000000000\n-1"), Ok(vec![3_000_000_000, -1]));
+        assert_eq!(
+            read_numbers("199\n20O\n\n\n208\nx"),
+            Err(vec![
+                DepthError::Malformed {
+                    line: 2,
+                    text: "20O".to_owned()
+                },
+                DepthError::Blank { line: 3 },
+                DepthError::Malformed {
+                    line: 6,
+                    text: "x".to_owned()
+                },
+            ])
+        );
+    }
+
+    #[test]
+    fn test_statistics() {
+        let statistics = statistics(&[199, 200, 208, 210, 200, 207, 240, 269, 260, 263]);
+        assert_eq!(statistics.longest_run, Some((0, 3)));
+        assert_eq!(statistics.largest_jump, Some((6, 33)));
+        assert_eq!(statistics.decreases, vec![4, 8]);
+        assert_eq!(statistics.deltas.get(&-10), Some(&1));
+        assert_eq!(statistics.deltas.values().sum::<usize>(), 9);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stream() {
+        use std::{fs::File, io::BufReader};
+
+        let answers = stream(BufReader::new(File::open("inputs/day1/input").unwrap()));
+        assert_eq!(answers, Ok((1226, 1252)));
+
+        let failure = stream(&b"1\n2\n\xff\n3\n"[..]).unwrap_err();
+        assert!(matches!(failure, Failure::Io(_)), "{:?}", failure);
+        assert_eq!(
+            stream(&b"1\n2\nx\n3\n"[..]),
+            Err(Failure::Parse(
+                "Day 1: cannot parse the input: line 3: \"x\" is not a depth".to_owned()
+            ))
+        );
+    }
+}
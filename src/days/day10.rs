@@ -0,0 +1,194 @@
+use crate::days::{Metadata, Solution};
+#[cfg(feature = "std")]
+use crate::outcome::{self, Failure};
+use crate::overflow;
+use crate::prelude::*;
+use itertools::Itertools;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+pub fn read_input(input: &str) -> Vec<String> {
+    input.lines().map(|line| line.to_owned()).collect()
+}
+
+fn score(ch: char) -> i32 {
+    match ch {
+        ')' => 3,
+        ']' => 57,
+        '}' => 1197,
+        '>' => 25137,
+        _ => 0,
+    }
+}
+
+fn match_and_score(ch1: char, ch2: char) -> (bool, i32) {
+    (
+        if ch1 == '(' {
+            ch2 == ')'
+        } else if ch1 == '[' {
+            ch2 == ']'
+        } else if ch1 == '{' {
+            ch2 == '}'
+        } else if ch1 == '<' {
+            ch2 == '>'
+        } else {
+            false
+        },
+        score(ch2),
+    )
+}
+
+fn score_stack(stack: &[char]) -> i64 {
+    stack.iter().rev().fold(0, |acc, ch| {
+        let value = match ch {
+            '(' => 1,
+            '[' => 2,
+            '{' => 3,
+            '<' => 4,
+            _ => panic!("fiasco"),
+        };
+        overflow::add(
+            Day10::DAY,
+            "completion score",
+            overflow::mul(Day10::DAY, "completion score * 5", acc, 5),
+            value,
+        )
+    })
+}
+
+fn syntax_error_score(line: &str) -> i32 {
+    let mut stack = Vec::new();
+    line.chars().fold(0, |acc, ch| {
+        match ch {
+            '(' | '[' | '{' | '<' => stack.push(ch),
+            _ => {
+                let prev = stack.pop();
+                if let Some(prev) = prev {
+                    let (matched, score) = match_and_score(prev, ch);
+                    if !matched {
+                        return overflow::add(Day10::DAY, "syntax error score", acc, score);
+                    }
+                }
+            }
+        };
+        acc
+    })
+}
+
+fn completion_score(line: &str) -> Option<i64> {
+    let mut stack = Vec::new();
+    for ch in line.chars() {
+        match ch {
+            '(' | '[' | '{' | '<' => stack.push(ch),
+            _ => {
+                let prev = stack.pop();
+                if let Some(prev) = prev {
+                    let (matched, _) = match_and_score(prev, ch);
+                    if !matched {
+                        return None;
+                    }
+                }
+            }
+        };
+    }
+    Some(score_stack(&stack))
+}
+
+fn middle_score(scores: &[i64]) -> i64 {
+    *scores.iter().sorted().nth(scores.len() / 2).unwrap()
+}
+
+pub fn part1(input: &[String]) -> i32 {
+    input.iter().fold(0, |acc, line| {
+        overflow::add(
+            Day10::DAY,
+            "total syntax error score",
+            acc,
+            syntax_error_score(line),
+        )
+    })
+}
+pub fn part2(input: &[String]) -> i64 {
+    let scores = input
+        .iter()
+        .filter_map(|line| completion_score(line))
+        .collect_vec();
+
+    middle_score(&scores)
+}
+
+/// Both parts in a single pass, holding a line at a time. Only the completion scores are kept,
+/// as there's no telling the middle one before seeing them all.
+#[cfg(feature = "std")]
+pub fn stream<R: BufRead>(reader: R) -> Result<(i32, i64), Failure> {
+    let mut total_syntax_error_score = 0;
+    let mut completion_scores = Vec::new();
+    for line in outcome::lines::<Day10>(reader) {
+        let (_, line) = line?;
+        total_syntax_error_score = overflow::add(
+            Day10::DAY,
+            "total syntax error score",
+            total_syntax_error_score,
+            syntax_error_score(&line),
+        );
+        completion_scores.extend(completion_score(&line));
+    }
+
+    Ok((total_syntax_error_score, middle_score(&completion_scores)))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const METADATA: Metadata = Metadata {
+        title: "Syntax Scoring",
+        part1: "syntax error score of the corrupted lines",
+        part2: "middle completion score of the incomplete lines",
+        params: &[],
+        visual: false,
+    };
+
+    type Input = Vec<String>;
+    type Part1 = i32;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Input {
+        read_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+
+    #[cfg(feature = "std")]
+    fn stream(reader: &mut dyn BufRead) -> Option<Result<(String, String), Failure>> {
+        Some(stream(reader).map(|(part1, part2)| (part1.to_string(), part2.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let input = read_input(&fs::read_to_string("inputs/day10/input").unwrap());
+        assert_eq!(part1(&input), 321237);
+        assert_eq!(part2(&input), 2360030859);
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stream() {
+        use std::{fs::File, io::BufReader};
+
+        let answers = stream(BufReader::new(File::open("inputs/day10/input").unwrap()));
+        assert_eq!(answers, Ok((321237, 2360030859)));
+    }
+}
@@ -0,0 +1,209 @@
+use crate::days::{single_param, Metadata, Solution};
+use crate::input_read::read_to_2d_byte_array;
+use crate::overflow;
+use crate::prelude::*;
+use crate::terminal::{bold, paint, Color};
+use core::convert::TryInto;
+use hashbrown::HashSet;
+
+fn flash(flashed: &mut HashSet<(i32, i32)>, input: &mut Vec<Vec<u8>>, x: i32, y: i32) -> i32 {
+    if x < 0
+        || y < 0
+        || x >= input.len().try_into().unwrap()
+        || y >= input.len().try_into().unwrap()
+    {
+        return 0;
+    }
+    let mut flashes = 0;
+    if input[x as usize][y as usize] > 9 {
+        flashes += 1;
+        flashed.insert((x, y));
+        input[x as usize][y as usize] = 0;
+        flashes += flash(flashed, input, x - 1, y - 1);
+        flashes += flash(flashed, input, x - 1, y);
+        flashes += flash(flashed, input, x - 1, y + 1);
+        flashes += flash(flashed, input, x, y - 1);
+        flashes += flash(flashed, input, x, y + 1);
+        flashes += flash(flashed, input, x + 1, y - 1);
+        flashes += flash(flashed, input, x + 1, y);
+        flashes += flash(flashed, input, x + 1, y + 1);
+    } else if !flashed.contains(&(x, y)) {
+        input[x as usize][y as usize] += 1;
+        if input[x as usize][y as usize] > 9 {
+            flashes += flash(flashed, input, x, y);
+        }
+    }
+
+    flashes
+}
+
+fn flash_all(input: &mut Vec<Vec<u8>>) -> (i32, bool) {
+    let mut flashed: HashSet<(i32, i32)> = HashSet::new();
+    let flashes = (0..input.len()).fold(0, |acc, x| {
+        acc + (0..input.len()).fold(0, |acc, y| {
+            acc + if input[x][y] > 9 {
+                flash(
+                    &mut flashed,
+                    input,
+                    x.try_into().unwrap(),
+                    y.try_into().unwrap(),
+                )
+            } else {
+                0
+            }
+        })
+    });
+
+    (flashes, flashed.len() == input.len() * input.len())
+}
+
+/// Advances the octopuses by a single step, returning the flashes and whether all of them flashed.
+fn step(input: &mut Vec<Vec<u8>>) -> (i32, bool) {
+    input
+        .iter_mut()
+        .for_each(|row| row.iter_mut().for_each(|cell| *cell += 1));
+
+    flash_all(input)
+}
+
+/// Energy levels after every step, along with the number of flashes in it.
+fn steps(input: &[Vec<u8>]) -> impl Iterator<Item = (Vec<Vec<u8>>, i32)> {
+    core::iter::repeat(()).scan(input.to_vec(), |input, _| {
+        let (flashes, _) = step(input);
+        Some((input.clone(), flashes))
+    })
+}
+
+fn render(step: usize, input: &[Vec<u8>], flashes: i32) -> String {
+    let mut frame = format!("Step {:>3}: {} flashes\n\n", step, bold(flashes));
+    for row in input {
+        for &cell in row {
+            frame += &match cell {
+                0 => bold(paint('█', Color::BrightWhite)),
+                1..=3 => paint('░', Color::Gray),
+                4..=6 => paint('▒', Color::Blue),
+                _ => paint('▓', Color::Cyan),
+            };
+        }
+        frame.push('\n');
+    }
+    frame
+}
+
+pub fn part1(input: &[Vec<u8>]) -> i32 {
+    let mut input = input.to_vec();
+
+    (1..=100).fold(0, |acc, _| {
+        overflow::add(Day11::DAY, "total flashes", acc, step(&mut input).0)
+    })
+}
+pub fn part2(input: &[Vec<u8>]) -> i32 {
+    let mut input = input.to_vec();
+
+    let mut step_no = 0;
+    loop {
+        step_no = overflow::add(Day11::DAY, "step number", step_no, 1);
+        if step(&mut input).1 {
+            return step_no;
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    const METADATA: Metadata = Metadata {
+        title: "Dumbo Octopus",
+        part1: "flashes after 100 steps",
+        part2: "first step when all octopuses flash",
+        params: &[(1, "STEPS")],
+        visual: true,
+    };
+
+    type Input = Vec<Vec<u8>>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Input {
+        read_to_2d_byte_array(input)
+    }
+
+    fn validate(input: &Self::Input) -> Vec<String> {
+        let mut violations = Vec::new();
+        if input.is_empty() {
+            violations.push("no octopuses".to_owned());
+        }
+        for (y, row) in input.iter().enumerate() {
+            if row.len() != input.len() {
+                violations.push(format!(
+                    "row {} has {} octopuses, the grid is not square",
+                    y + 1,
+                    row.len()
+                ));
+            }
+            if let Some(x) = row.iter().position(|&energy| energy > 9) {
+                violations.push(format!(
+                    "row {}, column {}: not an energy level",
+                    y + 1,
+                    x + 1
+                ));
+            }
+        }
+        violations
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn frames(input: &Self::Input) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        let size = input.len() * input.len();
+        let mut synchronized = false;
+        let frames = steps(input)
+            .take_while(move |(_, flashes)| {
+                !core::mem::replace(&mut synchronized, *flashes as usize == size)
+            })
+            .enumerate()
+            .map(|(step, (input, flashes))| render(step + 1, &input, flashes));
+        Some(Box::new(frames))
+    }
+
+    fn part_with(input: &Self::Input, part: u8, params: &[&str]) -> Result<String, String> {
+        if part != 1 {
+            return Err(format!("Part {} of day 11 takes no parameters", part));
+        }
+        let step_count = single_param(params)?;
+        let flashes = steps(input).take(step_count).map(|(_, flashes)| flashes);
+        Ok(overflow::sum(Day11::DAY, "total flashes", flashes).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let input = read_to_2d_byte_array(&fs::read_to_string("inputs/day11/input").unwrap());
+        assert_eq!(part1(&input), 1637);
+        assert_eq!(part2(&input), 242);
+        assert!(Day11::validate(&input).is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            Day11::validate(&Day11::parse("123\n45\n67:\n")),
+            vec![
+                "row 2 has 2 octopuses, the grid is not square",
+                "row 3, column 3: not an energy level"
+            ]
+        );
+    }
+}
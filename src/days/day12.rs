@@ -0,0 +1,181 @@
+use crate::days::{Metadata, Solution};
+use crate::memo::Memo;
+use crate::overflow;
+use crate::prelude::*;
+use crate::trace;
+use crate::trace::Level;
+use alloc::collections::BTreeSet;
+use hashbrown::HashMap;
+use itertools::Itertools;
+
+pub fn read_input(input: &str) -> Vec<(String, String)> {
+    input
+        .lines()
+        .map(|line| {
+            line.split('-')
+                .map(|s| s.to_owned())
+                .collect_tuple()
+                .unwrap()
+        })
+        .collect_vec()
+}
+
+type Visited = BTreeSet<String>;
+
+/// Small caves visited so far; big caves can be visited any number of times, so aren't recorded.
+fn visit(visited: &Visited, cave: &str) -> Visited {
+    let mut new = visited.clone();
+    if cave.chars().all(|ch| ch.is_lowercase()) {
+        new.insert(cave.to_owned());
+    }
+    new
+}
+
+fn explore_twice(
+    tunnels: &HashMap<String, Vec<String>>,
+    memo: &mut Memo<(String, Visited, bool), i32>,
+    cave: &str,
+    visited: Visited,
+    visited_twice: bool,
+) -> i32 {
+    memo.get(
+        (cave.to_owned(), visited, visited_twice),
+        |memo, (cave, visited, visited_twice)| {
+            tunnels.get(cave).unwrap().iter().fold(0, |paths, next| {
+                let is_lowercase = next.chars().all(|ch| ch.is_lowercase());
+                let next_paths = if next == "end" {
+                    1
+                } else if !is_lowercase
+                    || !visited.contains(next)
+                    || !visited_twice && next != "start"
+                {
+                    let visited_twice = *visited_twice || is_lowercase && visited.contains(next);
+                    explore_twice(tunnels, memo, next, visit(visited, next), visited_twice)
+                } else {
+                    0
+                };
+                overflow::add(Day12::DAY, "count of paths", paths, next_paths)
+            })
+        },
+    )
+}
+
+fn explore(
+    tunnels: &HashMap<String, Vec<String>>,
+    memo: &mut Memo<(String, Visited), i32>,
+    cave: &str,
+    visited: Visited,
+) -> i32 {
+    memo.get((cave.to_owned(), visited), |memo, (cave, visited)| {
+        tunnels.get(cave).unwrap().iter().fold(0, |paths, next| {
+            let next_paths = if next == "end" {
+                1
+            } else if !next.chars().any(|ch| ch.is_lowercase()) || !visited.contains(next) {
+                explore(tunnels, memo, next, visit(visited, next))
+            } else {
+                0
+            };
+            overflow::add(Day12::DAY, "count of paths", paths, next_paths)
+        })
+    })
+}
+
+fn input_to_map(input: &[(String, String)]) -> HashMap<String, Vec<String>> {
+    let mut tunnels: HashMap<String, Vec<String>> = HashMap::new();
+
+    for entry in input {
+        tunnels
+            .entry(entry.0.to_owned())
+            .or_default()
+            .push(entry.1.to_owned());
+        tunnels
+            .entry(entry.1.to_owned())
+            .or_default()
+            .push(entry.0.to_owned());
+    }
+    tunnels
+}
+
+pub fn part1(input: &[(String, String)]) -> i32 {
+    let tunnels = input_to_map(input);
+    let mut memo = Memo::new();
+    let start = visit(&Visited::new(), "start");
+    let paths = explore(&tunnels, &mut memo, "start", start);
+    trace!(
+        Day12::DAY,
+        Level::Info,
+        "{} cache hits, {} misses",
+        memo.hits(),
+        memo.misses()
+    );
+    paths
+}
+
+pub fn part2(input: &[(String, String)]) -> i32 {
+    let tunnels = input_to_map(input);
+    let mut memo = Memo::new();
+    let start = visit(&Visited::new(), "start");
+    let paths = explore_twice(&tunnels, &mut memo, "start", start, false);
+    trace!(
+        Day12::DAY,
+        Level::Info,
+        "{} cache hits, {} misses",
+        memo.hits(),
+        memo.misses()
+    );
+    paths
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const METADATA: Metadata = Metadata {
+        title: "Passage Pathing",
+        part1: "paths visiting small caves at most once",
+        part2: "paths visiting a single small cave twice",
+        params: &[],
+        visual: false,
+    };
+
+    type Input = Vec<(String, String)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Input {
+        read_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn show(input: &Self::Input) -> Option<String> {
+        let tunnels = input_to_map(input);
+        let shown = tunnels
+            .iter()
+            .sorted()
+            .map(|(cave, neighbours)| {
+                format!("{} -> {}", cave, neighbours.iter().sorted().join(", "))
+            })
+            .join("\n");
+        Some(shown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let input = read_input(&fs::read_to_string("inputs/day12/input").unwrap());
+        assert_eq!(part1(&input), 5228);
+        assert_eq!(part2(&input), 131228);
+    }
+}
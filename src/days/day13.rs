@@ -0,0 +1,265 @@
+use self::Fold::{Horizontal, Vertical};
+use crate::days::{Metadata, Solution};
+use crate::overflow;
+use crate::prelude::*;
+use crate::trace;
+use crate::trace::Level;
+use core::cmp::Ordering;
+use hashbrown::HashSet;
+use itertools::Itertools;
+
+#[derive(PartialEq, Eq, Hash, Clone)]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+pub enum Fold {
+    Horizontal(i32),
+    Vertical(i32),
+}
+
+pub fn read_input(input: &str) -> (Vec<Point>, Vec<Fold>) {
+    let mut points = Vec::new();
+    let mut folds = Vec::new();
+
+    input.lines().for_each(|line| {
+        if line.starts_with("fold") {
+            let (direction, value) = line.split('=').collect_tuple().unwrap();
+            let value = value.parse().unwrap();
+            if direction == "fold along y" {
+                folds.push(Horizontal(value));
+            } else {
+                folds.push(Vertical(value));
+            }
+        } else if !line.is_empty() {
+            let (x, y) = line.split(',').collect_tuple().unwrap();
+            points.push(Point {
+                x: x.parse().unwrap(),
+                y: y.parse().unwrap(),
+            });
+        }
+    });
+
+    (points, folds)
+}
+
+type Sheet = HashSet<Point>;
+
+fn fold_sheet(sheet: Sheet, fold: &Fold) -> Sheet {
+    let mut folded_sheet: HashSet<Point> = HashSet::new();
+    match fold {
+        Horizontal(val) => {
+            for point in sheet.iter() {
+                match point.y.cmp(val) {
+                    Ordering::Less => {
+                        folded_sheet.insert(point.clone());
+                    }
+                    Ordering::Equal => {
+                        panic!()
+                    }
+                    Ordering::Greater => {
+                        folded_sheet.insert(Point {
+                            y: *val
+                                - overflow::sub(Day13::DAY, "fold along y", point.y, *val).abs(),
+                            x: point.x,
+                        });
+                    }
+                }
+            }
+        }
+        Vertical(val) => {
+            for point in sheet.iter() {
+                match point.x.cmp(val) {
+                    Ordering::Less => {
+                        folded_sheet.insert(point.clone());
+                    }
+                    Ordering::Equal => {
+                        panic!()
+                    }
+                    Ordering::Greater => {
+                        folded_sheet.insert(Point {
+                            x: *val
+                                - overflow::sub(Day13::DAY, "fold along x", point.x, *val).abs(),
+                            y: point.y,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    let (axis, val) = match fold {
+        Horizontal(val) => ('y', val),
+        Vertical(val) => ('x', val),
+    };
+    trace!(
+        Day13::DAY,
+        Level::Info,
+        "fold along {}={}: {} dots left",
+        axis,
+        val,
+        folded_sheet.len()
+    );
+    folded_sheet
+}
+
+pub fn part1(points: &[Point], folds: &[Fold]) -> usize {
+    let sheet = HashSet::from_iter(points.iter().cloned());
+    fold_sheet(sheet, folds.first().unwrap()).len()
+}
+pub fn part2(points: &[Point], folds: &[Fold]) -> usize {
+    let sheet = HashSet::from_iter(points.iter().cloned());
+    folds.iter().fold(sheet, fold_sheet).len()
+}
+
+/// The code read from the dots left after all folds.
+pub fn code(points: &[Point], folds: &[Fold]) -> String {
+    let sheet = HashSet::from_iter(points.iter().cloned());
+    let sheet = folds.iter().fold(sheet, fold_sheet);
+
+    let mut result = [[' '; 40]; 6];
+    for point in sheet.iter() {
+        result[point.y as usize][point.x as usize] = '#';
+    }
+
+    result
+        .iter()
+        .map(|line| line.iter().collect::<String>())
+        .join("\n")
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    const METADATA: Metadata = Metadata {
+        title: "Transparent Origami",
+        part1: "dots visible after the first fold",
+        part2: "dots visible after all folds, drawing the code",
+        params: &[],
+        visual: true,
+    };
+
+    type Input = (Vec<Point>, Vec<Fold>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        read_input(input)
+    }
+
+    fn validate((points, folds): &Self::Input) -> Vec<String> {
+        let mut violations = Vec::new();
+        for point in points.iter().filter(|point| point.x < 0 || point.y < 0) {
+            violations.push(format!("dot {},{} is off the sheet", point.x, point.y));
+        }
+
+        // fold the dots one by one, as a dot on a line or folded past the edge breaks the folding
+        for point in points.iter().filter(|point| point.x >= 0 && point.y >= 0) {
+            let (mut x, mut y) = (i64::from(point.x), i64::from(point.y));
+            for fold in folds {
+                let (axis, line, coordinate) = match fold {
+                    Vertical(line) => ('x', i64::from(*line), &mut x),
+                    Horizontal(line) => ('y', i64::from(*line), &mut y),
+                };
+                if *coordinate == line {
+                    violations.push(format!(
+                        "dot {},{} lies on the fold along {}={}",
+                        point.x, point.y, axis, line
+                    ));
+                    break;
+                }
+                if *coordinate > line {
+                    *coordinate = 2 * line - *coordinate;
+                }
+                if *coordinate < 0 {
+                    violations.push(format!(
+                        "dot {},{} is folded off the sheet along {}={}",
+                        point.x, point.y, axis, line
+                    ));
+                    break;
+                }
+            }
+        }
+
+        // every fold leaves the dots before its line, so the tightest one bounds the code
+        let width = folds
+            .iter()
+            .filter_map(|fold| match fold {
+                Vertical(x) => Some(*x),
+                Horizontal(_) => None,
+            })
+            .min()
+            .or_else(|| points.iter().map(|point| point.x + 1).max());
+        let height = folds
+            .iter()
+            .filter_map(|fold| match fold {
+                Horizontal(y) => Some(*y),
+                Vertical(_) => None,
+            })
+            .min()
+            .or_else(|| points.iter().map(|point| point.y + 1).max());
+        if let (Some(width), Some(height)) = (width, height) {
+            if width > 40 || height > 6 {
+                violations.push(format!(
+                    "folded sheet is {}x{}, the code fits in 40x6",
+                    width, height
+                ));
+            }
+        }
+        violations
+    }
+
+    fn part1((first, second): &Self::Input) -> Self::Part1 {
+        part1(first, second)
+    }
+
+    fn part2((first, second): &Self::Input) -> Self::Part2 {
+        part2(first, second)
+    }
+
+    fn drawing((points, folds): &Self::Input) -> Option<String> {
+        Some(code(points, folds))
+    }
+
+    fn frames((points, folds): &Self::Input) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        Some(Box::new(core::iter::once(code(points, folds))))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let (points, folds) = read_input(&fs::read_to_string("inputs/day13/input").unwrap());
+        assert_eq!(part1(&points, &folds), 687);
+        assert_eq!(part2(&points, &folds), 98);
+        assert_eq!(
+            code(&points, &folds).lines().next(),
+            Some("####  ##  #  #  ##  #  # ###  ####  ##  ")
+        );
+        assert!(Day13::validate(&(points, folds)).is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = Day13::parse("1,2\n50,8\n\nfold along y=7\n");
+        assert_eq!(
+            Day13::validate(&input),
+            vec!["folded sheet is 51x7, the code fits in 40x6"]
+        );
+
+        let input = Day13::parse("1,10\n3,3\n2,1\n\nfold along y=3\n");
+        assert_eq!(
+            Day13::validate(&input),
+            vec![
+                "dot 1,10 is folded off the sheet along y=3",
+                "dot 3,3 lies on the fold along y=3"
+            ]
+        );
+    }
+}
@@ -0,0 +1,125 @@
+use crate::days::{single_param, Metadata, Solution};
+use crate::overflow;
+use crate::prelude::*;
+use hashbrown::HashMap;
+use itertools::Itertools;
+
+pub fn read_input(input: &str) -> (String, HashMap<(char, char), char>) {
+    let mut template = String::new();
+    let mut rules = HashMap::new();
+
+    for line in input.lines().filter(|line| !line.is_empty()) {
+        if template.is_empty() {
+            template = line.to_owned();
+        } else {
+            let (k, _, v) = line.split(' ').collect_tuple().unwrap();
+            rules.insert(
+                k.chars().collect_tuple().unwrap(),
+                v.chars().next().unwrap(),
+            );
+        }
+    }
+
+    (template, rules)
+}
+
+fn increase(count: &mut usize, by: usize) {
+    *count = overflow::add(Day14::DAY, "count of elements", *count, by);
+}
+
+fn solve(template: &str, rules: &HashMap<(char, char), char>, steps: usize) -> usize {
+    let mut polychunks: HashMap<(char, char), usize> = HashMap::new();
+
+    for chunk in template.chars().tuple_windows() {
+        *polychunks.entry(chunk).or_insert(0) += 1;
+    }
+
+    let mut counts: HashMap<char, usize> = HashMap::new();
+    for element in template.chars() {
+        increase(counts.entry(element).or_insert(0), 1);
+    }
+    for _ in 0..steps {
+        let mut new_polychunks: HashMap<(char, char), usize> = HashMap::new();
+        for (chunk, count) in polychunks {
+            if let Some(new_chunk) = rules.get(&chunk) {
+                increase(
+                    new_polychunks.entry((chunk.0, *new_chunk)).or_insert(0),
+                    count,
+                );
+                increase(
+                    new_polychunks.entry((*new_chunk, chunk.1)).or_insert(0),
+                    count,
+                );
+                increase(counts.entry(*new_chunk).or_insert(0), count);
+            } else {
+                increase(new_polychunks.entry(chunk).or_insert(0), count);
+            }
+        }
+
+        polychunks = new_polychunks;
+    }
+
+    let counts = counts
+        .iter()
+        .sorted_by(|&kv1, &kv2| kv1.1.cmp(kv2.1))
+        .map(|(_, v)| v)
+        .collect_vec();
+    **counts.last().unwrap() - **counts.first().unwrap()
+}
+
+pub fn part1(template: &str, rules: &HashMap<(char, char), char>) -> usize {
+    solve(template, rules, 10)
+}
+pub fn part2(template: &str, rules: &HashMap<(char, char), char>) -> usize {
+    solve(template, rules, 40)
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    const METADATA: Metadata = Metadata {
+        title: "Extended Polymerization",
+        part1: "most minus least common element after 10 steps",
+        part2: "most minus least common element after 40 steps",
+        params: &[(1, "STEPS"), (2, "STEPS")],
+        visual: false,
+    };
+
+    type Input = (String, HashMap<(char, char), char>);
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        read_input(input)
+    }
+
+    fn part1((first, second): &Self::Input) -> Self::Part1 {
+        part1(first, second)
+    }
+
+    fn part2((first, second): &Self::Input) -> Self::Part2 {
+        part2(first, second)
+    }
+
+    fn part_with(
+        (template, rules): &Self::Input,
+        _part: u8,
+        params: &[&str],
+    ) -> Result<String, String> {
+        Ok(solve(template, rules, single_param(params)?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let (template, rules) = read_input(&fs::read_to_string("inputs/day14/input").unwrap());
+        assert_eq!(part1(&template, &rules), 2797);
+        assert_eq!(part2(&template, &rules), 2926813379532);
+    }
+}
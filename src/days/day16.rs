@@ -0,0 +1,292 @@
+use self::Packet::{LiteralValue, Operator};
+use crate::days::{Metadata, Solution};
+use crate::overflow;
+use crate::prelude::*;
+use crate::trace;
+use crate::trace::Level;
+
+pub fn read_input(input: &str) -> String {
+    input.lines().next().unwrap().to_owned()
+}
+
+fn hex_to_binary(s: &str) -> String {
+    s.chars().fold(String::new(), |bin, hex| {
+        let num = u32::from_str_radix(&hex.to_string(), 16).unwrap();
+        bin + &format!("{:04b}", num)
+    })
+}
+
+enum Packet {
+    LiteralValue(ValuePacket),
+    Operator(OperatorPacket),
+}
+
+struct ValuePacket {
+    version: u32,
+    value: u64,
+}
+
+struct OperatorPacket {
+    version: u32,
+    type_id: u32,
+    packets: Vec<Packet>,
+}
+
+fn read_packets(packet: &str, mut idx: usize) -> (Packet, usize) {
+    let start = idx;
+    let version = u32::from_str_radix(&packet[idx..idx + 3], 2).unwrap();
+    idx += 3;
+    let type_id = u32::from_str_radix(&packet[idx..idx + 3], 2).unwrap();
+    idx += 3;
+    trace!(
+        Day16::DAY,
+        Level::Info,
+        "packet at bit {}: version {}, type {}",
+        start,
+        version,
+        type_id
+    );
+
+    let content = if type_id == 4 {
+        let mut value = String::new();
+        while &packet[idx..idx + 1] == "1" {
+            value.push_str(&packet[idx + 1..idx + 5]);
+            idx += 5;
+        }
+        value.push_str(&packet[idx + 1..idx + 5]);
+        idx += 5;
+
+        let value_packet = ValuePacket {
+            version,
+            value: u64::from_str_radix(&value, 2).unwrap(),
+        };
+        trace!(
+            Day16::DAY,
+            Level::Debug,
+            "literal value {}",
+            value_packet.value
+        );
+        Packet::LiteralValue(value_packet)
+    } else {
+        let length_type_id = &packet[idx..idx + 1];
+        idx += 1;
+
+        let mut sub_packets = Vec::new();
+        if length_type_id == "0" {
+            let mut total_in_bits = usize::from_str_radix(&packet[idx..idx + 15], 2).unwrap();
+            idx += 15;
+            trace!(
+                Day16::DAY,
+                Level::Debug,
+                "operator over {} bits of sub-packets",
+                total_in_bits
+            );
+
+            while total_in_bits > 0 {
+                let (sub_packet, new_idx) = read_packets(packet, idx);
+                sub_packets.push(sub_packet);
+                total_in_bits -= new_idx - idx;
+                idx = new_idx;
+            }
+        } else {
+            let total_packets = usize::from_str_radix(&packet[idx..idx + 11], 2).unwrap();
+            idx += 11;
+            trace!(
+                Day16::DAY,
+                Level::Debug,
+                "operator over {} sub-packets",
+                total_packets
+            );
+
+            for _ in 0..total_packets {
+                let (sub_packet, new_idx) = read_packets(packet, idx);
+                sub_packets.push(sub_packet);
+                idx = new_idx;
+            }
+        }
+        let operator_packet = OperatorPacket {
+            version,
+            type_id,
+            packets: sub_packets,
+        };
+        Packet::Operator(operator_packet)
+    };
+
+    (content, idx)
+}
+
+fn show_packet(packet: &Packet, depth: usize, shown: &mut String) {
+    let indent = "  ".repeat(depth);
+    match packet {
+        LiteralValue(val) => {
+            *shown += &format!(
+                "{}literal {} (version {})\n",
+                indent, val.value, val.version
+            )
+        }
+        Operator(val) => {
+            let operation = match val.type_id {
+                0 => "sum",
+                1 => "product",
+                2 => "minimum",
+                3 => "maximum",
+                5 => "greater than",
+                6 => "less than",
+                7 => "equal to",
+                _ => "unknown",
+            };
+            *shown += &format!("{}{} (version {})\n", indent, operation, val.version);
+            for packet in &val.packets {
+                show_packet(packet, depth + 1, shown);
+            }
+        }
+    }
+}
+
+fn sum_versions(packet: &Packet) -> u32 {
+    match packet {
+        LiteralValue(val) => val.version,
+        Packet::Operator(val) => {
+            let versions = overflow::sum(
+                Day16::DAY,
+                "sum of versions",
+                val.packets.iter().map(sum_versions),
+            );
+            overflow::add(Day16::DAY, "sum of versions", val.version, versions)
+        }
+    }
+}
+
+fn calculate_packets(packet: &Packet) -> u64 {
+    match packet {
+        LiteralValue(val) => val.value,
+        Operator(val) => match val.type_id {
+            0 => overflow::sum(
+                Day16::DAY,
+                "sum packet",
+                val.packets.iter().map(calculate_packets),
+            ),
+            1 => overflow::product(
+                Day16::DAY,
+                "product packet",
+                val.packets.iter().map(calculate_packets),
+            ),
+            2 => val.packets.iter().map(calculate_packets).min().unwrap(),
+            3 => val.packets.iter().map(calculate_packets).max().unwrap(),
+            5 => {
+                if calculate_packets(val.packets.first().unwrap())
+                    > calculate_packets(val.packets.get(1).unwrap())
+                {
+                    1
+                } else {
+                    0
+                }
+            }
+            6 => {
+                if calculate_packets(val.packets.first().unwrap())
+                    < calculate_packets(val.packets.get(1).unwrap())
+                {
+                    1
+                } else {
+                    0
+                }
+            }
+            7 => {
+                if calculate_packets(val.packets.first().unwrap())
+                    == calculate_packets(val.packets.get(1).unwrap())
+                {
+                    1
+                } else {
+                    0
+                }
+            }
+            _ => panic!("fiasco"),
+        },
+    }
+}
+
+pub fn part1(packet: &str) -> u32 {
+    let stream = hex_to_binary(packet);
+    let bits_packet = read_packets(&stream, 0);
+
+    sum_versions(&bits_packet.0)
+}
+pub fn part2(packet: &str) -> u64 {
+    let stream = hex_to_binary(packet);
+    let bits_packet = read_packets(&stream, 0);
+
+    calculate_packets(&bits_packet.0)
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+    const METADATA: Metadata = Metadata {
+        title: "Packet Decoder",
+        part1: "sum of the packet versions",
+        part2: "value of the outermost packet",
+        params: &[],
+        visual: false,
+    };
+
+    type Input = String;
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        read_input(input)
+    }
+
+    fn validate(input: &Self::Input) -> Vec<String> {
+        let mut violations = Vec::new();
+        if input.is_empty() {
+            violations.push("no transmission".to_owned());
+        }
+        let invalid = input
+            .char_indices()
+            .filter(|(_, ch)| !ch.is_ascii_hexdigit())
+            .map(|(i, ch)| format!("{:?} at {}", ch, i + 1))
+            .collect::<Vec<_>>();
+        if !invalid.is_empty() {
+            violations.push(format!("not hexadecimal: {}", invalid.join(", ")));
+        }
+        violations
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn show(input: &Self::Input) -> Option<String> {
+        let mut shown = String::new();
+        show_packet(&read_packets(&hex_to_binary(input), 0).0, 0, &mut shown);
+        Some(shown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let input = read_input(&fs::read_to_string("inputs/day16/input").unwrap());
+        assert_eq!(part1(&input), 938);
+        assert_eq!(part2(&input), 1495959086337);
+        assert!(Day16::validate(&input).is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            Day16::validate(&Day16::parse("D2FE2X\n")),
+            vec!["not hexadecimal: 'X' at 6"]
+        );
+    }
+}
@@ -0,0 +1,213 @@
+use crate::days::{Metadata, Solution};
+use crate::overflow;
+use crate::prelude::*;
+use crate::terminal::{bold, paint, Color};
+use itertools::Itertools;
+
+#[derive(Debug)]
+pub struct TargetArea {
+    x_min: i32,
+    x_max: i32,
+    y_min: i32,
+    y_max: i32,
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+impl TargetArea {
+    fn contains(&self, pos: &Point) -> bool {
+        pos.x >= self.x_min && pos.x <= self.x_max && pos.y >= self.y_min && pos.y <= self.y_max
+    }
+}
+
+pub fn read_input(input: &str) -> TargetArea {
+    let matches: Vec<i32> = input
+        .lines()
+        .next()
+        .unwrap()
+        .split(|ch: char| !ch.is_ascii_digit() && ch != '-')
+        .filter_map(|num| num.parse().ok())
+        .collect();
+    TargetArea {
+        x_min: matches[0],
+        x_max: matches[1],
+        y_min: matches[2],
+        y_max: matches[3],
+    }
+}
+
+fn try_simulate(target_area: &TargetArea, mut vel_x: i32, mut vel_y: i32) -> Option<i32> {
+    let mut pos = Point::default();
+    let mut max_height = pos.y;
+
+    while pos.x < target_area.x_max && pos.y > target_area.y_min {
+        pos.x = overflow::add(Day17::DAY, "probe x position", pos.x, vel_x);
+        pos.y = overflow::add(Day17::DAY, "probe y position", pos.y, vel_y);
+
+        if target_area.contains(&pos) {
+            return Some(max_height);
+        }
+
+        vel_x = 0.max(vel_x - 1);
+
+        if vel_y == 0 {
+            max_height = pos.y;
+        }
+        vel_y -= 1;
+    }
+
+    None
+}
+
+/// Probe positions after every step of its flight.
+struct Flight {
+    pos: Point,
+    vel_x: i32,
+    vel_y: i32,
+}
+
+impl Iterator for Flight {
+    type Item = Point;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.pos.x += self.vel_x;
+        self.pos.y += self.vel_y;
+        self.vel_x = 0.max(self.vel_x - 1);
+        self.vel_y -= 1;
+        Some(self.pos)
+    }
+}
+
+fn highest_velocity(target_area: &TargetArea) -> (i32, i32) {
+    (1..=target_area.x_max)
+        .cartesian_product(target_area.y_min..100)
+        .max_by_key(|&(vel_x, vel_y)| try_simulate(target_area, vel_x, vel_y))
+        .unwrap()
+}
+
+/// Positions of the probe from the launch until it either hits or misses the target area.
+fn trace_flight(target_area: &TargetArea, vel_x: i32, vel_y: i32) -> Vec<Point> {
+    let mut flight = vec![Point::default()];
+    for pos in (Flight {
+        pos: Point::default(),
+        vel_x,
+        vel_y,
+    }) {
+        flight.push(pos);
+        if target_area.contains(&pos) || pos.x > target_area.x_max || pos.y < target_area.y_min {
+            break;
+        }
+    }
+    flight
+}
+
+fn render(target_area: &TargetArea, velocity: (i32, i32), flight: &[Point], top: i32) -> String {
+    const WIDTH: i32 = 72;
+    const HEIGHT: i32 = 36;
+    let column = |x: i32| (x * (WIDTH - 1) / target_area.x_max.max(1)) as usize;
+    let row = |y: i32| ((top - y) * (HEIGHT - 1) / (top - target_area.y_min).max(1)) as usize;
+
+    let mut canvas = vec![vec![' '.to_string(); WIDTH as usize]; HEIGHT as usize];
+    for line in canvas
+        .iter_mut()
+        .take(row(target_area.y_min) + 1)
+        .skip(row(target_area.y_max))
+    {
+        for cell in line
+            .iter_mut()
+            .take(column(target_area.x_max) + 1)
+            .skip(column(target_area.x_min))
+        {
+            *cell = paint('█', Color::Red);
+        }
+    }
+    let visible = |pos: &Point| pos.x <= target_area.x_max && pos.y >= target_area.y_min;
+    for pos in flight.iter().filter(|pos| visible(pos)) {
+        canvas[row(pos.y)][column(pos.x)] = paint('·', Color::Gray);
+    }
+    let probe = flight.last().unwrap();
+    if visible(probe) {
+        canvas[row(probe.y)][column(probe.x)] = bold(paint('o', Color::Green));
+    }
+
+    let mut frame = format!(
+        "Velocity {:?}, step {:>3}, height {}\n\n",
+        velocity,
+        flight.len() - 1,
+        bold(probe.y)
+    );
+    for line in canvas {
+        frame += &line.concat();
+        frame.push('\n');
+    }
+    frame
+}
+
+pub fn part1(target_area: &TargetArea) -> i32 {
+    (1..=target_area.x_max)
+        .cartesian_product(target_area.y_min..100)
+        .filter_map(|(vel_x, vel_y)| try_simulate(target_area, vel_x, vel_y))
+        .max()
+        .unwrap()
+}
+pub fn part2(target_area: &TargetArea) -> usize {
+    (1..=target_area.x_max)
+        .cartesian_product(target_area.y_min..100)
+        .filter(|&(vel_x, vel_y)| try_simulate(target_area, vel_x, vel_y).is_some())
+        .count()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+    const METADATA: Metadata = Metadata {
+        title: "Trick Shot",
+        part1: "highest position reachable while hitting the target",
+        part2: "initial velocities hitting the target",
+        params: &[],
+        visual: true,
+    };
+
+    type Input = TargetArea;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        read_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn frames(input: &Self::Input) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        let velocity = highest_velocity(input);
+        let flight = trace_flight(input, velocity.0, velocity.1);
+        let top = flight.iter().map(|pos| pos.y).max().unwrap();
+        let frames =
+            (1..=flight.len()).map(move |steps| render(input, velocity, &flight[..steps], top));
+        Some(Box::new(frames))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let target_area = read_input(&fs::read_to_string("inputs/day17/input").unwrap());
+        assert_eq!(part1(&target_area), 4095);
+        assert_eq!(part2(&target_area), 3773);
+    }
+}
@@ -0,0 +1,614 @@
+use crate::days::{Metadata, Solution};
+#[cfg(feature = "std")]
+use crate::outcome::{self, Failure};
+use crate::overflow;
+use crate::prelude::*;
+use core::fmt;
+use core::str::FromStr;
+use itertools::Itertools;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Instruction {
+    Forward(i32),
+    Down(i32),
+    Up(i32),
+    /// Forward in reverse, so with the aim the depth changes the other way, too.
+    Back(i32),
+    /// Turns the submarine straight to the given aim; the plain model has none to set.
+    SetAim(i32),
+}
+
+impl fmt::Display for Instruction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Instruction::Forward(val) => write!(f, "forward {}", val),
+            Instruction::Down(val) => write!(f, "down {}", val),
+            Instruction::Up(val) => write!(f, "up {}", val),
+            Instruction::Back(val) => write!(f, "back {}", val),
+            Instruction::SetAim(val) => write!(f, "set aim {}", val),
+        }
+    }
+}
+
+/// A statement of the command language, with the instructions as leaves.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Instruction(Instruction),
+    Repeat { times: u32, body: Vec<Command> },
+}
+
+/// Where the commands went wrong.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Token<'a> {
+    Word(&'a str),
+    Open,
+    Close,
+}
+
+/// Splits the line into words and braces, leaving out the comment after `#`.
+fn lex<'a>(line: usize, text: &'a str, tokens: &mut Vec<(usize, Token<'a>)>) {
+    let code = text.split('#').next().unwrap_or_default();
+    for word in code.split_whitespace() {
+        let mut rest = word;
+        while !rest.is_empty() {
+            let (token, len) = match rest.find(['{', '}']) {
+                Some(0) if rest.starts_with('{') => (Token::Open, 1),
+                Some(0) => (Token::Close, 1),
+                Some(i) => (Token::Word(&rest[..i]), i),
+                None => (Token::Word(rest), rest.len()),
+            };
+            tokens.push((line, token));
+            rest = &rest[len..];
+        }
+    }
+}
+
+struct Parser<'a> {
+    tokens: alloc::vec::IntoIter<(usize, Token<'a>)>,
+    /// Line of the last token taken, for the errors.
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(tokens: Vec<(usize, Token<'a>)>) -> Self {
+        Parser {
+            tokens: tokens.into_iter(),
+            line: 1,
+        }
+    }
+
+    fn next(&mut self) -> Option<Token<'a>> {
+        let (line, token) = self.tokens.next()?;
+        self.line = line;
+        Some(token)
+    }
+
+    fn error(&self, message: String) -> ParseError {
+        ParseError {
+            line: self.line,
+            message,
+        }
+    }
+
+    fn number<T: FromStr>(&mut self, keyword: &str) -> Result<T, ParseError> {
+        match self.next() {
+            Some(Token::Word(word)) => word
+                .parse()
+                .map_err(|_| self.error(format!("{} takes a number, not {:?}", keyword, word))),
+            _ => Err(self.error(format!("{} takes a number", keyword))),
+        }
+    }
+
+    /// Commands up to the end of the input or, inside a block opened on the given line, up to
+    /// its closing brace.
+    fn commands(&mut self, block: Option<usize>) -> Result<Vec<Command>, ParseError> {
+        let mut commands = Vec::new();
+        loop {
+            let instruction = match self.next() {
+                None => {
+                    return match block {
+                        Some(line) => Err(ParseError {
+                            line,
+                            message: "repeat block is never closed".to_owned(),
+                        }),
+                        None => Ok(commands),
+                    }
+                }
+                Some(Token::Close) if block.is_some() => return Ok(commands),
+                Some(Token::Close) => return Err(self.error("} closes no block".to_owned())),
+                Some(Token::Open) => return Err(self.error("{ opens no repeat".to_owned())),
+                Some(Token::Word("forward")) => Instruction::Forward(self.number("forward")?),
+                Some(Token::Word("down")) => Instruction::Down(self.number("down")?),
+                Some(Token::Word("up")) => Instruction::Up(self.number("up")?),
+                Some(Token::Word("back")) => Instruction::Back(self.number("back")?),
+                Some(Token::Word("set")) => match self.next() {
+                    Some(Token::Word("aim")) => Instruction::SetAim(self.number("set aim")?),
+                    _ => return Err(self.error("set only sets the aim".to_owned())),
+                },
+                Some(Token::Word("repeat")) => {
+                    let line = self.line;
+                    let times = self.number("repeat")?;
+                    if self.next() != Some(Token::Open) {
+                        return Err(self.error("repeat takes a block in braces".to_owned()));
+                    }
+                    let body = self.commands(Some(line))?;
+                    commands.push(Command::Repeat { times, body });
+                    continue;
+                }
+                Some(Token::Word(word)) => {
+                    return Err(self.error(format!("unknown command {:?}", word)))
+                }
+            };
+            commands.push(Command::Instruction(instruction));
+        }
+    }
+}
+
+/// Parses the commands: an instruction, `repeat N { ... }` or a `#` comment wherever they fit.
+pub fn read_program(input: &str) -> Result<Vec<Command>, ParseError> {
+    let mut tokens = Vec::new();
+    for (i, text) in input.lines().enumerate() {
+        lex(i + 1, text, &mut tokens);
+    }
+    Parser::new(tokens).commands(None)
+}
+
+/// Runs the program from the given state, steering as the model says.
+pub fn interpret(program: &[Command], model: Model, state: State) -> State {
+    program.iter().fold(state, |state, command| match command {
+        Command::Instruction(instr) => state.steer(model, instr),
+        Command::Repeat { times, body } => {
+            (0..*times).fold(state, |state, _| interpret(body, model, state))
+        }
+    })
+}
+
+/// The instructions the program goes through, in order, with the blocks repeated.
+pub fn unroll(program: &[Command]) -> Vec<Instruction> {
+    let mut instructions = Vec::new();
+    for command in program {
+        match command {
+            Command::Instruction(instr) => instructions.push(*instr),
+            Command::Repeat { times, body } => {
+                let body = unroll(body);
+                for _ in 0..*times {
+                    instructions.extend_from_slice(&body);
+                }
+            }
+        }
+    }
+    instructions
+}
+
+fn steer((pos, depth): (i32, i32), instr: &Instruction) -> (i32, i32) {
+    match instr {
+        Instruction::Up(val) => (pos, overflow::sub(Day2::DAY, "depth - up", depth, *val)),
+        Instruction::Down(val) => (pos, overflow::add(Day2::DAY, "depth + down", depth, *val)),
+        Instruction::Forward(val) => (overflow::add(Day2::DAY, "pos + forward", pos, *val), depth),
+        Instruction::Back(val) => (overflow::sub(Day2::DAY, "pos - back", pos, *val), depth),
+        Instruction::SetAim(_) => (pos, depth),
+    }
+}
+
+fn steer_with_aim((pos, depth, aim): (i32, i32, i32), instr: &Instruction) -> (i32, i32, i32) {
+    match instr {
+        Instruction::Up(val) => (pos, depth, overflow::sub(Day2::DAY, "aim - up", aim, *val)),
+        Instruction::Down(val) => (
+            pos,
+            depth,
+            overflow::add(Day2::DAY, "aim + down", aim, *val),
+        ),
+        Instruction::Forward(val) => (
+            overflow::add(Day2::DAY, "pos + forward", pos, *val),
+            overflow::add(
+                Day2::DAY,
+                "depth + aim * forward",
+                depth,
+                overflow::mul(Day2::DAY, "aim * forward", aim, *val),
+            ),
+            aim,
+        ),
+        Instruction::Back(val) => (
+            overflow::sub(Day2::DAY, "pos - back", pos, *val),
+            overflow::sub(
+                Day2::DAY,
+                "depth - aim * back",
+                depth,
+                overflow::mul(Day2::DAY, "aim * back", aim, *val),
+            ),
+            aim,
+        ),
+        Instruction::SetAim(val) => (pos, depth, *val),
+    }
+}
+
+pub fn part1(program: &[Command]) -> i32 {
+    let state = interpret(program, Model::Plain, State::default());
+    overflow::mul(Day2::DAY, "pos * depth", state.pos, state.depth)
+}
+pub fn part2(program: &[Command]) -> i32 {
+    let state = interpret(program, Model::Aimed, State::default());
+    overflow::mul(Day2::DAY, "pos * depth", state.pos, state.depth)
+}
+
+/// How the instructions are read: `Plain` moves the depth straight away, as in part 1, `Aimed`
+/// turns the submarine instead, as in part 2.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Model {
+    Plain,
+    Aimed,
+}
+
+impl FromStr for Model {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "plain" => Ok(Model::Plain),
+            "aimed" => Ok(Model::Aimed),
+            _ => Err(format!("Unknown model: {}, expected plain or aimed", s)),
+        }
+    }
+}
+
+/// Where the submarine is after an instruction. The aim stays 0 with the plain model.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct State {
+    pub pos: i32,
+    pub depth: i32,
+    pub aim: i32,
+}
+
+impl State {
+    pub fn steer(self, model: Model, instr: &Instruction) -> State {
+        match model {
+            Model::Plain => {
+                let (pos, depth) = steer((self.pos, self.depth), instr);
+                State { pos, depth, aim: 0 }
+            }
+            Model::Aimed => {
+                let (pos, depth, aim) = steer_with_aim((self.pos, self.depth, self.aim), instr);
+                State { pos, depth, aim }
+            }
+        }
+    }
+}
+
+/// States after every instruction, starting from the surface.
+pub fn trajectory(instructions: &[Instruction], model: Model) -> impl Iterator<Item = State> + '_ {
+    instructions
+        .iter()
+        .scan(State::default(), move |state, instr| {
+            *state = state.steer(model, instr);
+            Some(*state)
+        })
+}
+
+/// The trajectory as CSV, a row per instruction numbered from 1.
+pub fn to_csv(states: &[State]) -> String {
+    let rows = states
+        .iter()
+        .enumerate()
+        .map(|(i, state)| format!("{},{},{},{}\n", i + 1, state.pos, state.depth, state.aim));
+    format!("step,position,depth,aim\n{}", rows.collect::<String>())
+}
+
+const SVG_WIDTH: i64 = 800;
+const SVG_HEIGHT: i64 = 300;
+
+/// Depth against the horizontal position, from the surface on, as an SVG line with the depth
+/// growing downwards. Both axes are stretched to fit, so steep dives look steeper than they are.
+pub fn depth_profile_svg(states: &[State]) -> String {
+    let points: Vec<(i64, i64)> = core::iter::once(State::default())
+        .chain(states.iter().copied())
+        .map(|state| (i64::from(state.pos), i64::from(state.depth)))
+        .collect();
+    let (min_pos, max_pos) = points
+        .iter()
+        .map(|&(pos, _)| pos)
+        .minmax()
+        .into_option()
+        .unwrap_or((0, 0));
+    let (min_depth, max_depth) = points
+        .iter()
+        .map(|&(_, depth)| depth)
+        .minmax()
+        .into_option()
+        .unwrap_or((0, 0));
+
+    let scale =
+        |value: i64, min: i64, max: i64, size: i64| (value - min) * size / (max - min).max(1);
+    let polyline = points
+        .iter()
+        .map(|&(pos, depth)| {
+            format!(
+                "{},{}",
+                scale(pos, min_pos, max_pos, SVG_WIDTH),
+                scale(depth, min_depth, max_depth, SVG_HEIGHT)
+            )
+        })
+        .join(" ");
+
+    format!(
+        concat!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"-10 -10 {} {}\">\n",
+            "  <title>Depth from {} to {}, position from {} to {}</title>\n",
+            "  <polyline fill=\"none\" stroke=\"navy\" stroke-width=\"2\" points=\"{}\"/>\n",
+            "</svg>\n"
+        ),
+        SVG_WIDTH + 20,
+        SVG_HEIGHT + 20,
+        min_depth,
+        max_depth,
+        min_pos,
+        max_pos,
+        polyline
+    )
+}
+
+/// A shortest list of the puzzle's own instructions, `forward`, `down` and `up` by positive
+/// amounts, taking the submarine from the surface to the position and depth, if it can get there.
+///
+/// The submarine never moves back, so the position can't be negative. Plainly, it takes a move
+/// per coordinate that isn't 0. With the aim, the depth only changes moving forward, so a depth
+/// off the surface needs an aim and a move after it: just those two when the position divides the
+/// depth, and otherwise a move of all but the last step first, whose aim then makes up the depth.
+pub fn plan((pos, depth): (i32, i32), model: Model) -> Option<Vec<Instruction>> {
+    let vertical = |depth: i32| match depth.signum() {
+        1 => Some(Instruction::Down(depth)),
+        -1 => Some(Instruction::Up(depth.checked_neg()?)),
+        _ => None,
+    };
+    let forward = (pos > 0).then_some(Instruction::Forward(pos));
+
+    match model {
+        _ if pos < 0 => None,
+        Model::Plain => {
+            let plan: Vec<_> = forward.into_iter().collect();
+            match depth {
+                0 => Some(plan),
+                _ => Some([vec![vertical(depth)?], plan].concat()),
+            }
+        }
+        Model::Aimed if depth == 0 => Some(forward.into_iter().collect()),
+        Model::Aimed if pos == 0 => None,
+        Model::Aimed if depth % pos == 0 => Some(vec![vertical(depth / pos)?, forward?]),
+        Model::Aimed => Some(vec![
+            Instruction::Forward(pos - 1),
+            vertical(depth)?,
+            Instruction::Forward(1),
+        ]),
+    }
+}
+
+/// Both parts in a single pass, steering the two submarines side by side.
+#[cfg(feature = "std")]
+pub fn stream<R: BufRead>(reader: R) -> Result<(i32, i32), Failure> {
+    let mut submarine = (0, 0);
+    let mut aimed_submarine = (0, 0, 0);
+    for line in outcome::lines::<Day2>(reader) {
+        let (line, text) = line?;
+        let mut tokens = Vec::new();
+        lex(line, &text, &mut tokens);
+        // a block can't be run before its end, which may be anywhere further down
+        if tokens
+            .iter()
+            .any(|&(_, token)| token == Token::Open || token == Token::Close)
+        {
+            return Err(outcome::parse_failure::<Day2>(format!(
+                "line {}: repeat blocks can't be streamed",
+                line
+            )));
+        }
+
+        let program = Parser::new(tokens)
+            .commands(None)
+            .map_err(outcome::parse_failure::<Day2>)?;
+        for instr in unroll(&program) {
+            submarine = steer(submarine, &instr);
+            aimed_submarine = steer_with_aim(aimed_submarine, &instr);
+        }
+    }
+
+    let ((pos, depth), (aimed_pos, aimed_depth, _)) = (submarine, aimed_submarine);
+    Ok((
+        overflow::mul(Day2::DAY, "pos * depth", pos, depth),
+        overflow::mul(Day2::DAY, "pos * depth", aimed_pos, aimed_depth),
+    ))
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+    const METADATA: Metadata = Metadata {
+        title: "Dive!",
+        part1: "horizontal position times depth",
+        part2: "horizontal position times depth, steering with the aim",
+        params: &[],
+        visual: false,
+    };
+
+    type Input = Vec<Command>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Input {
+        read_program(input).unwrap_or_else(|err| panic!("{}", err))
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+
+    #[cfg(feature = "std")]
+    fn stream(reader: &mut dyn BufRead) -> Option<Result<(String, String), Failure>> {
+        Some(stream(reader).map(|(part1, part2)| (part1.to_string(), part2.to_string())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let program = read_program(&fs::read_to_string("inputs/day2/input").unwrap()).unwrap();
+        assert_eq!(part1(&program), 1938402);
+        assert_eq!(part2(&program), 1947878632);
+    }
+
+    #[test]
+    fn test_program() {
+        let program = read_program(
+            "# warm up\nrepeat 2 {\n    forward 5 # steady\n    down 2\n}\nset aim 10\nback 1\n",
+        )
+        .unwrap();
+        assert_eq!(
+            program[0],
+            Command::Repeat {
+                times: 2,
+                body: vec![
+                    Command::Instruction(Instruction::Forward(5)),
+                    Command::Instruction(Instruction::Down(2))
+                ]
+            }
+        );
+        assert_eq!(unroll(&program).len(), 6);
+        let state = |pos, depth, aim| State { pos, depth, aim };
+        assert_eq!(
+            interpret(&program, Model::Plain, State::default()),
+            state(9, 4, 0)
+        );
+        assert_eq!(
+            interpret(&program, Model::Aimed, State::default()),
+            state(9, 0, 10)
+        );
+
+        let program = read_program("repeat 3 {forward 1} repeat 2 { repeat 2 { up 1 } }").unwrap();
+        assert_eq!(part1(&program), -12);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        let error = |input| read_program(input).unwrap_err().to_string();
+        assert_eq!(
+            error("forward 5\nsideways 3"),
+            "line 2: unknown command \"sideways\""
+        );
+        assert_eq!(error("down x"), "line 1: down takes a number, not \"x\"");
+        assert_eq!(error("forward"), "line 1: forward takes a number");
+        assert_eq!(error("set depth 3"), "line 1: set only sets the aim");
+        assert_eq!(error("up 1\n}"), "line 2: } closes no block");
+        assert_eq!(
+            error("up 1\nrepeat 3 {\n  up 1\n"),
+            "line 2: repeat block is never closed"
+        );
+        assert_eq!(
+            error("repeat 3\nup 1"),
+            "line 2: repeat takes a block in braces"
+        );
+    }
+
+    #[test]
+    fn test_trajectory() {
+        let instructions =
+            unroll(&read_program("forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2").unwrap());
+        let plain: Vec<State> = trajectory(&instructions, Model::Plain).collect();
+        let aimed: Vec<State> = trajectory(&instructions, Model::Aimed).collect();
+        assert_eq!(plain.len(), 6);
+        assert_eq!(
+            plain[5],
+            State {
+                pos: 15,
+                depth: 10,
+                aim: 0
+            }
+        );
+        assert_eq!(
+            aimed[2],
+            State {
+                pos: 13,
+                depth: 40,
+                aim: 5
+            }
+        );
+        assert_eq!(
+            aimed[5],
+            State {
+                pos: 15,
+                depth: 60,
+                aim: 10
+            }
+        );
+
+        let csv = to_csv(&aimed);
+        assert!(csv.starts_with("step,position,depth,aim\n1,5,0,0\n2,5,0,5\n"));
+        assert_eq!(csv.lines().count(), 7);
+        assert!(depth_profile_svg(&aimed)
+            .contains("points=\"0,0 266,0 266,0 693,200 693,200 693,200 800,300\""));
+    }
+
+    #[test]
+    fn test_plan() {
+        for model in [Model::Plain, Model::Aimed] {
+            for target in (-2..8).cartesian_product(-12..12) {
+                let Some(plan) = plan(target, model) else {
+                    continue;
+                };
+                let reached = trajectory(&plan, model).last().unwrap_or_default();
+                assert_eq!((reached.pos, reached.depth), target, "{:?}", model);
+                assert!(plan.iter().all(|instr| match instr {
+                    Instruction::Forward(val) | Instruction::Down(val) | Instruction::Up(val) =>
+                        *val > 0,
+                    _ => false,
+                }));
+            }
+        }
+
+        let len = |target, model| plan(target, model).map(|plan| plan.len());
+        assert_eq!(len((0, 0), Model::Plain), Some(0));
+        assert_eq!(len((5, -3), Model::Plain), Some(2));
+        assert_eq!(len((-1, 0), Model::Plain), None);
+        assert_eq!(len((0, 3), Model::Aimed), None);
+        assert_eq!(len((3, 0), Model::Aimed), Some(1));
+        assert_eq!(len((3, -9), Model::Aimed), Some(2));
+        assert_eq!(len((3, 10), Model::Aimed), Some(3));
+        assert_eq!(
+            plan((15, 60), Model::Aimed).unwrap().iter().join("\n"),
+            "down 4\nforward 15"
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_stream() {
+        use std::{fs::File, io::BufReader};
+
+        let answers = stream(BufReader::new(File::open("inputs/day2/input").unwrap()));
+        assert_eq!(answers, Ok((1938402, 1947878632)));
+        assert!(matches!(
+            stream(&b"forward 1\nrepeat 2 {\n"[..]),
+            Err(Failure::Parse(_))
+        ));
+    }
+}
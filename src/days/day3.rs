@@ -0,0 +1,440 @@
+use crate::days::{Metadata, Solution};
+use crate::prelude::*;
+use crate::trace;
+use crate::trace::Level;
+use core::fmt;
+use core::str::FromStr;
+use itertools::Itertools;
+use num_bigint::BigUint;
+
+/// A report packed 64 bits to a word, the last word holding its least significant bits.
+/// Reports of the same width are ordered by their values.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Report {
+    width: usize,
+    words: Vec<u64>,
+}
+
+impl Report {
+    fn zero(width: usize) -> Self {
+        Report {
+            width,
+            words: vec![0; width.div_ceil(64)],
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Bit at position `i` of the report as written, i.e. counting from the most significant one.
+    pub fn bit(&self, i: usize) -> bool {
+        let (word, shift) = self.locate(i);
+        self.words[word] >> shift & 1 == 1
+    }
+
+    fn set(&mut self, i: usize) {
+        let (word, shift) = self.locate(i);
+        self.words[word] |= 1 << shift;
+    }
+
+    fn locate(&self, i: usize) -> (usize, usize) {
+        let from_end = self.width - 1 - i;
+        (self.words.len() - 1 - from_end / 64, from_end % 64)
+    }
+
+    pub fn value(&self) -> BigUint {
+        let bytes: Vec<u8> = self
+            .words
+            .iter()
+            .flat_map(|word| word.to_be_bytes())
+            .collect();
+        BigUint::from_bytes_be(&bytes)
+    }
+}
+
+impl FromStr for Report {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut report = Report::zero(s.len());
+        for (i, bit) in s.chars().enumerate() {
+            match bit {
+                '0' => {}
+                '1' => report.set(i),
+                _ => return Err(format!("not binary: {}", s)),
+            }
+        }
+        Ok(report)
+    }
+}
+
+pub fn read_diagnostics(input: &str) -> Result<Vec<Report>, Vec<String>> {
+    let (reports, errors): (Vec<_>, Vec<_>) = input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            line.parse()
+                .map_err(|err| format!("line {} is {}", i + 1, err))
+        })
+        .partition_result();
+    if errors.is_empty() {
+        Ok(reports)
+    } else {
+        Err(errors)
+    }
+}
+
+pub fn part1(diagnostics: &[Report]) -> BigUint {
+    let width = diagnostics.first().unwrap().width();
+
+    let mut gamma_rate = Report::zero(width);
+    let mut epsilon_rate = Report::zero(width);
+    for i in 0..width {
+        let ones = diagnostics.iter().filter(|report| report.bit(i)).count();
+        if ones > diagnostics.len() - ones {
+            gamma_rate.set(i);
+        } else {
+            epsilon_rate.set(i);
+        }
+    }
+    gamma_rate.value() * epsilon_rate.value()
+}
+
+/// Which bits a rating keeps, position by position.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Criteria {
+    /// Keeps the most common bit if true, the least common one otherwise.
+    pub most_common: bool,
+    /// The bit kept when both are as common.
+    pub on_tie: bool,
+}
+
+impl Criteria {
+    pub const OXYGEN_GENERATOR: Criteria = Criteria {
+        most_common: true,
+        on_tie: true,
+    };
+    pub const CO2_SCRUBBER: Criteria = Criteria {
+        most_common: false,
+        on_tie: false,
+    };
+
+    fn keep_ones(self, zeros: usize, ones: usize) -> bool {
+        if zeros == ones {
+            self.on_tie
+        } else {
+            (ones > zeros) == self.most_common
+        }
+    }
+}
+
+/// Written as `most:1` or `least:0`, i.e. which bits are kept and which one wins ties.
+impl FromStr for Criteria {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let most_common = match s.split(':').next() {
+            Some("most") => true,
+            Some("least") => false,
+            _ => {
+                return Err(format!(
+                    "Unknown criteria: {}, expected most:BIT or least:BIT",
+                    s
+                ))
+            }
+        };
+        let on_tie = match s.split(':').nth(1) {
+            Some("1") => true,
+            Some("0") => false,
+            _ => return Err(format!("Criteria {} don't tell the bit kept on ties", s)),
+        };
+        Ok(Criteria {
+            most_common,
+            on_tie,
+        })
+    }
+}
+
+impl fmt::Display for Criteria {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let common = if self.most_common { "most" } else { "least" };
+        write!(f, "{}:{}", common, u8::from(self.on_tie))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rating<'a> {
+    /// None when the criteria kept a bit no candidate had.
+    pub report: Option<&'a Report>,
+    /// Candidates left after each bit, up to the one leaving at most one.
+    pub remaining: Vec<usize>,
+}
+
+/// Narrows the sorted reports down to a rating, bit by bit. The candidates share the bits so far,
+/// so they make up a range of the sorted reports, which the next bit splits in two: those with a 0
+/// first.
+fn rating<'a>(sorted: &[&'a Report], criteria: Criteria) -> Rating<'a> {
+    let width = sorted.first().map_or(0, |report| report.width());
+    let (mut start, mut end) = (0, sorted.len());
+    let mut remaining = Vec::new();
+    for i in 0..width {
+        if end - start <= 1 {
+            break;
+        }
+        let split = start + sorted[start..end].partition_point(|report| !report.bit(i));
+        if criteria.keep_ones(split - start, end - split) {
+            start = split;
+        } else {
+            end = split;
+        }
+        remaining.push(end - start);
+        trace!(
+            Day3::DAY,
+            Level::Debug,
+            "{} after bit {}: {} candidates left",
+            criteria,
+            i,
+            end - start
+        );
+    }
+
+    Rating {
+        report: sorted[start..end].first().copied(),
+        remaining,
+    }
+}
+
+/// Ratings by each of the criteria, in O(n log n) to sort the reports and then O(w log n) each.
+pub fn ratings<'a>(diagnostics: &'a [Report], criteria: &[Criteria]) -> Vec<Rating<'a>> {
+    let mut sorted: Vec<&Report> = diagnostics.iter().collect();
+    sorted.sort_unstable();
+    criteria
+        .iter()
+        .map(|&criteria| rating(&sorted, criteria))
+        .collect()
+}
+
+/// Product of the oxygen generator and CO2 scrubber ratings, by the given criteria.
+pub fn life_support_rating(diagnostics: &[Report], oxygen: Criteria, co2: Criteria) -> BigUint {
+    ratings(diagnostics, &[oxygen, co2])
+        .iter()
+        .zip(["oxygen generator", "CO2 scrubber"])
+        .map(|(rating, name)| match rating.report {
+            Some(report) => report.value(),
+            None => panic!("no {} rating", name),
+        })
+        .product()
+}
+
+pub fn part2(diagnostics: &[Report]) -> BigUint {
+    life_support_rating(
+        diagnostics,
+        Criteria::OXYGEN_GENERATOR,
+        Criteria::CO2_SCRUBBER,
+    )
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+    const METADATA: Metadata = Metadata {
+        title: "Binary Diagnostic",
+        part1: "gamma rate times epsilon rate",
+        part2: "oxygen generator rating times CO2 scrubber rating",
+        params: &[(2, "OXYGEN"), (2, "CO2")],
+        visual: false,
+    };
+
+    type Input = Vec<Report>;
+    type Part1 = BigUint;
+    type Part2 = BigUint;
+
+    fn parse(input: &str) -> Self::Input {
+        read_diagnostics(input).unwrap_or_else(|errors| panic!("{}", errors.join("\n    ")))
+    }
+
+    fn validate(diagnostics: &Self::Input) -> Vec<String> {
+        let width = match diagnostics.first() {
+            Some(first) => first.width(),
+            None => return vec!["no diagnostics".to_owned()],
+        };
+
+        let mut violations = Vec::new();
+        if width == 0 {
+            violations.push("the first line is empty".to_owned());
+        }
+        for (i, diagnostic) in diagnostics.iter().enumerate() {
+            if diagnostic.width() != width {
+                violations.push(format!(
+                    "line {} is {} bits wide, unlike the first one",
+                    i + 1,
+                    diagnostic.width()
+                ));
+            }
+        }
+        violations
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn part_with(input: &Self::Input, part: u8, params: &[&str]) -> Result<String, String> {
+        let (oxygen, co2) = match (part, params) {
+            (2, [oxygen, co2]) => (oxygen.parse()?, co2.parse()?),
+            (2, _) => return Err(format!("Expected 2 parameters, got {}", params.len())),
+            _ => return Err(format!("Part {} of day 3 takes no parameters", part)),
+        };
+        Ok(life_support_rating(input, oxygen, co2).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{assert_agrees, Random};
+    use std::{fs, iter};
+
+    #[test]
+    fn test_solution() {
+        let diagnostics =
+            read_diagnostics(&fs::read_to_string("inputs/day3/input").unwrap()).unwrap();
+        assert_eq!(part1(&diagnostics), BigUint::from(2743844u32));
+        assert_eq!(part2(&diagnostics), BigUint::from(6677951u32));
+        assert!(Day3::validate(&diagnostics).is_empty());
+    }
+
+    #[test]
+    fn test_validate() {
+        assert_eq!(
+            Day3::validate(&Day3::parse("0101\n011\n0111\n")),
+            vec!["line 2 is 3 bits wide, unlike the first one"]
+        );
+        assert_eq!(
+            read_diagnostics("0101\n0121\n01"),
+            Err(vec!["line 2 is not binary: 0121".to_owned()])
+        );
+    }
+
+    /// The ratings as first solved, filtering the candidates over and over.
+    fn ratings_by_filtering(diagnostics: &[Report]) -> (Option<Report>, Option<Report>) {
+        let width = diagnostics.first().unwrap().width();
+
+        let mut oxygen_ratings: Vec<_> = diagnostics.iter().collect();
+        let mut co2_scrubber_ratings: Vec<_> = diagnostics.iter().collect();
+
+        let bit_counter = |v: &Vec<&Report>, i| {
+            let ones = v.iter().filter(|report| report.bit(i)).count();
+            (v.len() - ones, ones)
+        };
+
+        for i in 0..width {
+            if oxygen_ratings.len() > 1 {
+                let bit = bit_counter(&oxygen_ratings, i);
+                let most_common = bit.1 >= bit.0;
+                oxygen_ratings.retain(|rating| rating.bit(i) == most_common);
+            }
+            if co2_scrubber_ratings.len() > 1 {
+                let bit = bit_counter(&co2_scrubber_ratings, i);
+                let least_common = bit.0 > bit.1;
+                co2_scrubber_ratings.retain(|rating| rating.bit(i) == least_common);
+            }
+        }
+
+        (
+            oxygen_ratings.first().copied().cloned(),
+            co2_scrubber_ratings.first().copied().cloned(),
+        )
+    }
+
+    fn life_support(diagnostics: &[Report]) -> (Option<Report>, Option<Report>) {
+        let ratings = ratings(
+            diagnostics,
+            &[Criteria::OXYGEN_GENERATOR, Criteria::CO2_SCRUBBER],
+        );
+        (ratings[0].report.cloned(), ratings[1].report.cloned())
+    }
+
+    #[test]
+    fn test_ratings() {
+        let diagnostics =
+            read_diagnostics(&fs::read_to_string("inputs/day3/input").unwrap()).unwrap();
+        // pseudo-random reports, duplicates and dead ends included
+        let mut random = Random::new(42);
+        let generated =
+            [(1, 3), (2, 1), (7, 3), (100, 6), (1000, 12), (3000, 70)].map(|(count, width)| {
+                let mut reports = (0..count).map(|_| {
+                    (0..width)
+                        .map(|_| if random.below(2) == 1 { '1' } else { '0' })
+                        .collect::<String>()
+                });
+                read_diagnostics(&reports.join("\n")).unwrap()
+            });
+
+        assert_agrees(
+            iter::once(diagnostics).chain(generated),
+            |diagnostics| life_support(diagnostics),
+            |diagnostics| ratings_by_filtering(diagnostics),
+        );
+    }
+
+    #[test]
+    fn test_criteria() {
+        let example = "00100 11110 10110 10111 10101 01111 00111 11100 10000 11001 00010 01010";
+        let diagnostics = read_diagnostics(&example.split(' ').join("\n")).unwrap();
+        let criteria: Vec<Criteria> = ["most:1", "least:0", "most:0", "least:1"]
+            .iter()
+            .map(|criteria| criteria.parse().unwrap())
+            .collect();
+        assert_eq!(criteria[0], Criteria::OXYGEN_GENERATOR);
+        assert_eq!(criteria[1], Criteria::CO2_SCRUBBER);
+        assert_eq!(criteria[3].to_string(), "least:1");
+        assert!("most".parse::<Criteria>().is_err());
+
+        let ratings = ratings(&diagnostics, &criteria);
+        let values: Vec<_> = ratings
+            .iter()
+            .map(|rating| rating.report.map(Report::value))
+            .collect();
+        assert_eq!(values[0], Some(BigUint::from(23u32)));
+        assert_eq!(values[1], Some(BigUint::from(10u32)));
+        assert_eq!(ratings[0].remaining, vec![7, 4, 3, 2, 1]);
+        assert_eq!(ratings[1].remaining, vec![5, 2, 1]);
+        // 01111 and 01010 tie on the last bits, going the other way
+        assert_eq!(values[2], Some(BigUint::from(22u32)));
+        assert_eq!(values[3], Some(BigUint::from(15u32)));
+        assert_eq!(
+            Day3::part_with(&diagnostics, 2, &["most:0", "least:1"]),
+            Ok("330".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_wide() {
+        let example = "00100 11110 10110 10111 10101 01111 00111 11100 10000 11001 00010 01010";
+        let diagnostics = read_diagnostics(&example.split(' ').join("\n")).unwrap();
+        assert_eq!(part1(&diagnostics), BigUint::from(198u32));
+        assert_eq!(part2(&diagnostics), BigUint::from(230u32));
+
+        // every report 20 times over, which the rates and ratings are, too
+        let wide = example
+            .split(' ')
+            .map(|report| report.repeat(20))
+            .join("\n");
+        let diagnostics = read_diagnostics(&wide).unwrap();
+        assert_eq!(diagnostics[0].width(), 100);
+        let repeated = |value: u32| {
+            (0..20u32).fold(BigUint::from(0u32), |sum, i| {
+                sum + (BigUint::from(value) << (5 * i))
+            })
+        };
+        assert_eq!(part1(&diagnostics), repeated(22) * repeated(9));
+        assert_eq!(part2(&diagnostics), repeated(23) * repeated(10));
+    }
+}
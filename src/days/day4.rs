@@ -0,0 +1,356 @@
+use crate::days::{Metadata, Solution};
+use crate::overflow;
+use crate::prelude::*;
+use crate::trace;
+use crate::trace::Level;
+use core::fmt;
+use hashbrown::{HashMap, HashSet};
+use itertools::Itertools;
+
+#[derive(Debug, Default, PartialEq, Clone)]
+pub struct Board {
+    data: Vec<Vec<i32>>,
+}
+
+impl fmt::Display for Board {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in &self.data {
+            writeln!(
+                f,
+                "{}",
+                row.iter().map(|num| format!("{:>2}", num)).join(" ")
+            )?;
+        }
+        Ok(())
+    }
+}
+
+pub fn read_input(input: &str) -> (Vec<i32>, Vec<Board>) {
+    let lines: Vec<&str> = input.lines().collect();
+    let numbers = lines
+        .first()
+        .unwrap()
+        .split(',')
+        .filter(|num| !num.is_empty())
+        .map(|num| {
+            num.parse()
+                .unwrap_or_else(|_| panic!("line 1: draw {:?} is not a number", num))
+        })
+        .collect();
+
+    let mut board = Board::default();
+    let mut boards = Vec::new();
+    for (i, line) in lines.iter().enumerate().skip(2) {
+        if line.is_empty() {
+            boards.push(board);
+            board = Board::default();
+        } else {
+            let row = line
+                .split_whitespace()
+                .map(|num| {
+                    num.parse()
+                        .unwrap_or_else(|_| panic!("line {}: {:?} is not a number", i + 1, num))
+                })
+                .collect();
+            board.data.push(row);
+        }
+    }
+
+    boards.push(board);
+
+    (numbers, boards)
+}
+
+/// A board winning, at the draw with the given index.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Win {
+    pub board: usize,
+    pub draw: usize,
+    pub score: i32,
+}
+
+/// Plays all the boards at once. Every number is indexed to the cells holding it, and the rows and
+/// columns count their marked cells, so a draw takes constant time per cell it marks.
+pub struct Bingo<'a> {
+    boards: &'a [Board],
+    /// Board, row and column of every cell, by its number.
+    cells: HashMap<i32, Vec<(usize, usize, usize)>>,
+    drawn: HashSet<i32>,
+    row_hits: Vec<Vec<usize>>,
+    column_hits: Vec<Vec<usize>>,
+    unmarked: Vec<i32>,
+    won: Vec<bool>,
+}
+
+impl<'a> Bingo<'a> {
+    pub fn new(boards: &'a [Board]) -> Self {
+        let mut cells: HashMap<i32, Vec<_>> = HashMap::new();
+        for (i, board) in boards.iter().enumerate() {
+            for (row, line) in board.data.iter().enumerate() {
+                for (column, &num) in line.iter().enumerate() {
+                    cells.entry(num).or_default().push((i, row, column));
+                }
+            }
+        }
+
+        Bingo {
+            boards,
+            cells,
+            drawn: HashSet::new(),
+            row_hits: boards
+                .iter()
+                .map(|board| vec![0; board.data.len()])
+                .collect(),
+            column_hits: boards
+                .iter()
+                .map(|board| vec![0; board.data.iter().map(Vec::len).max().unwrap_or(0)])
+                .collect(),
+            unmarked: boards
+                .iter()
+                .map(|board| {
+                    overflow::sum(
+                        Day4::DAY,
+                        "sum of unmarked numbers",
+                        board.data.iter().flatten().copied(),
+                    )
+                })
+                .collect(),
+            won: vec![false; boards.len()],
+        }
+    }
+
+    /// Marks the number, returning the boards it makes win, in order. Numbers drawn again mark
+    /// nothing new.
+    pub fn draw(&mut self, num: i32) -> Vec<usize> {
+        let mut winners = Vec::new();
+        if !self.drawn.insert(num) {
+            return winners;
+        }
+
+        for &(i, row, column) in self.cells.get(&num).into_iter().flatten() {
+            self.unmarked[i] =
+                overflow::sub(Day4::DAY, "sum of unmarked numbers", self.unmarked[i], num);
+            self.row_hits[i][row] += 1;
+            self.column_hits[i][column] += 1;
+
+            let data = &self.boards[i].data;
+            let full = self.row_hits[i][row] == data[row].len()
+                || self.column_hits[i][column] == data.len();
+            if full && !self.won[i] {
+                self.won[i] = true;
+                winners.push(i);
+            }
+        }
+        winners.sort_unstable();
+        winners
+    }
+
+    /// Score of the board after the last draw.
+    pub fn score(&self, board: usize, num: i32) -> i32 {
+        overflow::mul(Day4::DAY, "score", self.unmarked[board], num)
+    }
+}
+
+/// Every board winning, in the order they do, boards winning on the same draw by their index.
+pub fn wins<'a>(numbers: &'a [i32], boards: &'a [Board]) -> impl Iterator<Item = Win> + 'a {
+    let mut bingo = Bingo::new(boards);
+    numbers.iter().enumerate().flat_map(move |(draw, &num)| {
+        trace!(Day4::DAY, Level::Debug, "draw #{}: {}", draw + 1, num);
+        let winners = bingo.draw(num);
+        winners
+            .into_iter()
+            .map(|board| Win {
+                board,
+                draw,
+                score: bingo.score(board, num),
+            })
+            .collect::<Vec<_>>()
+    })
+}
+
+pub fn part1(numbers: &[i32], boards: &[Board]) -> i32 {
+    let win = wins(numbers, boards).next().expect("no solution!");
+    trace!(
+        Day4::DAY,
+        Level::Info,
+        "board {} wins first, score {}",
+        win.board,
+        win.score
+    );
+    win.score
+}
+pub fn part2(numbers: &[i32], boards: &[Board]) -> i32 {
+    for (i, win) in wins(numbers, boards).enumerate() {
+        trace!(
+            Day4::DAY,
+            Level::Info,
+            "board {} wins as {}/{}",
+            win.board,
+            i + 1,
+            boards.len()
+        );
+        if i + 1 == boards.len() {
+            trace!(
+                Day4::DAY,
+                Level::Info,
+                "board {} wins last, score {}",
+                win.board,
+                win.score
+            );
+            return win.score;
+        }
+    }
+    panic!("no solution!")
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+    const METADATA: Metadata = Metadata {
+        title: "Giant Squid",
+        part1: "score of the first board to win",
+        part2: "score of the last board to win",
+        params: &[],
+        visual: false,
+    };
+
+    type Input = (Vec<i32>, Vec<Board>);
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Input {
+        read_input(input)
+    }
+
+    fn validate((numbers, boards): &Self::Input) -> Vec<String> {
+        let mut violations = Vec::new();
+        if numbers.is_empty() {
+            violations.push("no numbers to draw".to_owned());
+        }
+        for (i, board) in boards.iter().enumerate() {
+            if board.data.len() != 5 || board.data.iter().any(|row| row.len() != 5) {
+                violations.push(format!(
+                    "board {} is not 5x5, its rows are {} long",
+                    i + 1,
+                    board.data.iter().map(|row| row.len()).join(", ")
+                ));
+            }
+        }
+        violations
+    }
+
+    fn part1((first, second): &Self::Input) -> Self::Part1 {
+        part1(first, second)
+    }
+
+    fn part2((first, second): &Self::Input) -> Self::Part2 {
+        part2(first, second)
+    }
+
+    fn show((numbers, boards): &Self::Input) -> Option<String> {
+        let mut shown = format!("Draws: {}\n", numbers.iter().join(","));
+        for (i, board) in boards.iter().enumerate() {
+            shown += &format!("\nBoard {}:\n{}", i, board);
+        }
+        Some(shown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::{assert_agrees, Random};
+    use std::{fs, iter};
+
+    #[test]
+    fn test_solution() {
+        let (numbers, boards) = read_input(&fs::read_to_string("inputs/day4/input").unwrap());
+        assert_eq!(part1(&numbers, &boards), 6592);
+        assert_eq!(part2(&numbers, &boards), 31755);
+        assert!(Day4::validate(&(numbers, boards)).is_empty());
+    }
+
+    /// The game as first played, scanning the draws so far for every cell of every board.
+    fn is_winning(board: &Board, nums: &[i32]) -> bool {
+        let column_count = board.data.first().unwrap().len();
+        board
+            .data
+            .iter()
+            .any(|row| row.iter().all(|cell| nums.contains(cell)))
+            || (0..column_count)
+                .any(|column| board.data.iter().all(|row| nums.contains(&row[column])))
+    }
+
+    fn scanned_wins(numbers: &[i32], boards: &[Board]) -> Vec<Win> {
+        let mut wins = Vec::new();
+        for draw in 0..numbers.len() {
+            let nums = &numbers[..=draw];
+            for (i, board) in boards.iter().enumerate() {
+                if is_winning(board, nums) && wins.iter().all(|win: &Win| win.board != i) {
+                    let unmarked: i32 = board
+                        .data
+                        .iter()
+                        .flatten()
+                        .filter(|num| !nums.contains(num))
+                        .sum();
+                    wins.push(Win {
+                        board: i,
+                        draw,
+                        score: unmarked * numbers[draw],
+                    });
+                }
+            }
+        }
+        wins
+    }
+
+    #[test]
+    fn test_bingo() {
+        let game = read_input(&fs::read_to_string("inputs/day4/input").unwrap());
+        // pseudo-random games over few numbers, so boards repeat them and draws come again
+        let mut random = Random::new(7);
+        let mut next = || random.below(30) as i32;
+        let generated: Vec<(Vec<i32>, Vec<Board>)> = (0..20)
+            .map(|_| {
+                let numbers = (0..40).map(|_| next()).collect();
+                let boards = (0..10)
+                    .map(|_| Board {
+                        data: (0..5).map(|_| (0..5).map(|_| next()).collect()).collect(),
+                    })
+                    .collect();
+                (numbers, boards)
+            })
+            .collect();
+
+        assert_agrees(
+            iter::once(game).chain(generated),
+            |(numbers, boards)| wins(numbers, boards).collect::<Vec<_>>(),
+            |(numbers, boards)| scanned_wins(numbers, boards),
+        );
+    }
+
+    #[test]
+    fn test_validate() {
+        let input = Day4::parse("\n\n1 2 3 4 5\n6 7 8 9\n");
+        assert_eq!(
+            Day4::validate(&input),
+            vec![
+                "no numbers to draw",
+                "board 1 is not 5x5, its rows are 5, 4 long"
+            ]
+        );
+    }
+
+    #[test]
+    #[should_panic(expected = "line 1: draw \"x\" is not a number")]
+    fn test_bad_draw() {
+        read_input(&format!("1,x,2\n\n{}", "1 2 3 4 5\n".repeat(5)));
+    }
+
+    #[test]
+    #[should_panic(expected = "line 8: \"y\" is not a number")]
+    fn test_bad_board_number() {
+        read_input(&format!("1,2\n\n{}1 2 y 4 5", "1 2 3 4 5\n".repeat(5)));
+    }
+}
@@ -0,0 +1,132 @@
+use crate::days::{Metadata, Solution};
+use crate::overflow;
+use crate::prelude::*;
+use core::str::FromStr;
+use hashbrown::HashMap;
+use itertools::Itertools;
+
+#[derive(Debug)]
+pub struct Line((i32, i32), (i32, i32));
+
+impl FromStr for Line {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let chunks: ((i32, i32), (i32, i32)) = s
+            .split(' ')
+            .filter(|&l| l != "->")
+            .map(|l| {
+                l.split(',')
+                    .map(|pos| pos.parse().unwrap())
+                    .collect_tuple()
+                    .unwrap()
+            })
+            .collect_tuple()
+            .unwrap();
+
+        Ok(Self(chunks.0, chunks.1))
+    }
+}
+
+pub fn read_input(input: &str) -> Vec<Line> {
+    input.lines().map(|line| line.parse().unwrap()).collect()
+}
+
+fn increment(count: &mut i32) {
+    *count = overflow::add(Day5::DAY, "count of overlapping lines", *count, 1);
+}
+
+pub fn part1(lines: &[Line]) -> i32 {
+    let mut map: HashMap<(i32, i32), i32> = HashMap::new();
+    for line in lines {
+        let min_x = line.0 .0.min(line.1 .0);
+        let max_x = line.0 .0.max(line.1 .0);
+        let min_y = line.0 .1.min(line.1 .1);
+        let max_y = line.0 .1.max(line.1 .1);
+        if min_x == max_x {
+            for y in min_y..=max_y {
+                increment(map.entry((min_x, y)).or_insert(0));
+            }
+        } else if min_y == max_y {
+            for x in min_x..=max_x {
+                increment(map.entry((x, min_y)).or_insert(0));
+            }
+        }
+    }
+
+    map.values().filter(|&&val| val > 1).count() as i32
+}
+pub fn part2(lines: &[Line]) -> i32 {
+    let mut map: HashMap<(i32, i32), i32> = HashMap::new();
+    for line in lines {
+        let min_x = line.0 .0.min(line.1 .0);
+        let max_x = line.0 .0.max(line.1 .0);
+        let min_y = line.0 .1.min(line.1 .1);
+        let max_y = line.0 .1.max(line.1 .1);
+        if min_x == max_x {
+            for y in min_y..=max_y {
+                increment(map.entry((min_x, y)).or_insert(0));
+            }
+        } else if min_y == max_y {
+            for x in min_x..=max_x {
+                increment(map.entry((x, min_y)).or_insert(0));
+            }
+        } else {
+            for i in 0..=(max_x - min_x) {
+                let (x, y) = if line.0 .0 < line.1 .0 && line.0 .1 < line.1 .1 {
+                    (min_x + i, min_y + i)
+                } else if line.0 .0 < line.1 .0 && line.0 .1 > line.1 .1 {
+                    (min_x + i, max_y - i)
+                } else if line.0 .0 > line.1 .0 && line.0 .1 < line.1 .1 {
+                    (max_x - i, min_y + i)
+                } else {
+                    (max_x - i, max_y - i)
+                };
+                increment(map.entry((x, y)).or_insert(0));
+            }
+        }
+    }
+    map.values().filter(|&&val| val > 1).count() as i32
+}
+
+pub struct Day5;
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+    const METADATA: Metadata = Metadata {
+        title: "Hydrothermal Venture",
+        part1: "points where horizontal and vertical lines overlap",
+        part2: "points where any lines overlap",
+        params: &[],
+        visual: false,
+    };
+
+    type Input = Vec<Line>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Self::Input {
+        read_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let lines = read_input(&fs::read_to_string("inputs/day5/input").unwrap());
+        assert_eq!(part1(&lines), 7085);
+        assert_eq!(part2(&lines), 20271);
+    }
+}
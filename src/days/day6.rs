@@ -0,0 +1,130 @@
+use crate::days::{single_param, Metadata, Solution};
+use crate::overflow;
+use crate::prelude::*;
+use crate::terminal::{bold, paint, Color};
+
+pub fn read_fish(input: &str) -> Vec<i32> {
+    input
+        .lines()
+        .next()
+        .unwrap()
+        .split(',')
+        .map(|num| num.parse().unwrap())
+        .collect()
+}
+
+/// Fish counts grouped by their timer, day after day.
+fn generations(fish: &[i32]) -> impl Iterator<Item = [u64; 9]> {
+    let mut fish_groups = [0u64; 9];
+    for fish in fish.iter() {
+        fish_groups[*fish as usize] += 1;
+    }
+
+    core::iter::successors(Some(fish_groups), |fish_groups| {
+        let mut fish_groups = *fish_groups;
+        fish_groups[7] = overflow::add(
+            Day6::DAY,
+            "fish with timer 7 + new parents",
+            fish_groups[7],
+            fish_groups[0],
+        );
+        fish_groups.rotate_left(1);
+        Some(fish_groups)
+    })
+}
+
+fn solve(fish: &[i32], days: usize) -> u64 {
+    overflow::sum(
+        Day6::DAY,
+        "total fish",
+        generations(fish).nth(days).unwrap(),
+    )
+}
+
+fn render(day: usize, fish_groups: &[u64; 9]) -> String {
+    const WIDTH: u64 = 60;
+    let largest = *fish_groups.iter().max().unwrap();
+
+    let mut frame = format!(
+        "Day {:>3}: {} lanternfish\n\n",
+        day,
+        bold(fish_groups.iter().sum::<u64>())
+    );
+    for (timer, count) in fish_groups.iter().enumerate() {
+        let bar = "█".repeat((count * WIDTH / largest.max(1)) as usize);
+        let color = if timer == 0 {
+            Color::Yellow
+        } else {
+            Color::Cyan
+        };
+        frame += &format!("timer {} {} {}\n", timer, paint(bar, color), count);
+    }
+    frame
+}
+
+pub fn part1(fish: &[i32]) -> u64 {
+    solve(fish, 80)
+}
+
+pub fn part2(fish: &[i32]) -> u64 {
+    solve(fish, 256)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+    const METADATA: Metadata = Metadata {
+        title: "Lanternfish",
+        part1: "lanternfish after 80 days",
+        part2: "lanternfish after 256 days",
+        params: &[(1, "DAYS"), (2, "DAYS")],
+        visual: true,
+    };
+
+    type Input = Vec<i32>;
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Self::Input {
+        read_fish(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+
+    fn frames(input: &Self::Input) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        let frames = generations(input)
+            .take(257)
+            .enumerate()
+            .map(|(day, fish_groups)| render(day, &fish_groups));
+        Some(Box::new(frames))
+    }
+
+    fn part_with(input: &Self::Input, _part: u8, params: &[&str]) -> Result<String, String> {
+        Ok(solve(input, single_param(params)?).to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let fish = read_fish(&fs::read_to_string("inputs/day6/input").unwrap());
+        assert_eq!(part1(&fish), 351188);
+        assert_eq!(part2(&fish), 1595779846729);
+        assert_eq!(
+            Day6::part_with(&fish, 1, &["0"]),
+            Ok(fish.len().to_string())
+        );
+        assert!(Day6::part_with(&fish, 1, &["-1"]).is_err());
+    }
+}
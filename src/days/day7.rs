@@ -0,0 +1,96 @@
+use crate::days::{Metadata, Solution};
+use crate::overflow;
+use crate::prelude::*;
+
+use itertools::Itertools;
+
+pub fn read_input(input: &str) -> Vec<i64> {
+    input
+        .lines()
+        .next()
+        .unwrap()
+        .split(',')
+        .map(|num| num.parse().unwrap())
+        .collect()
+}
+
+pub fn part1(positions: &[i64]) -> i64 {
+    let (min, max) = if let itertools::MinMaxResult::MinMax(min, max) = positions.iter().minmax() {
+        (*min, *max)
+    } else {
+        panic!("input fiasco!");
+    };
+
+    (min..=max)
+        .map(|level| {
+            positions.iter().fold(0, |sum, pos| {
+                let distance = i64::abs(overflow::sub(Day7::DAY, "distance", level, *pos));
+                overflow::add(Day7::DAY, "total fuel", sum, distance)
+            })
+        })
+        .min()
+        .unwrap()
+}
+pub fn part2(positions: &[i64]) -> i64 {
+    let (min, max) = if let itertools::MinMaxResult::MinMax(min, max) = positions.iter().minmax() {
+        (*min, *max)
+    } else {
+        panic!("input fiasco!");
+    };
+
+    let sequence_sum = |distance: i64| {
+        overflow::mul(Day7::DAY, "fuel of a single crab", distance, distance + 1) / 2
+    };
+
+    (min..=max)
+        .map(|level| {
+            positions.iter().fold(0, |sum, pos| {
+                let distance = i64::abs(overflow::sub(Day7::DAY, "distance", level, *pos));
+                overflow::add(Day7::DAY, "total fuel", sum, sequence_sum(distance))
+            })
+        })
+        .min()
+        .unwrap()
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+    const METADATA: Metadata = Metadata {
+        title: "The Treachery of Whales",
+        part1: "fuel to align the crabs, one unit per step",
+        part2: "fuel to align the crabs, costlier with every step",
+        params: &[],
+        visual: false,
+    };
+
+    type Input = Vec<i64>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Self::Input {
+        read_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let positions = read_input(&fs::read_to_string("inputs/day7/input").unwrap());
+        assert_eq!(part1(&positions), 342534);
+        assert_eq!(part2(&positions), 94004208);
+    }
+}
@@ -0,0 +1,211 @@
+use crate::days::{Metadata, Solution};
+use crate::overflow;
+use crate::prelude::*;
+use crate::trace;
+use crate::trace::Level;
+use itertools::Itertools;
+
+pub type SingleInput = (Vec<String>, Vec<String>);
+pub type AllInput = Vec<SingleInput>;
+
+pub fn read_input(input: &str) -> AllInput {
+    input
+        .lines()
+        .map(|l| {
+            let (patterns, output) = l.split('|').collect_tuple().unwrap();
+            (
+                patterns.split_whitespace().map(|n| n.to_owned()).collect(),
+                output.split_whitespace().map(|n| n.to_owned()).collect(),
+            )
+        })
+        .collect()
+}
+
+/// Candidate wires of each segment, named `a` to `g` as in the puzzle.
+fn describe(candidates: &[Vec<char>]) -> String {
+    candidates
+        .iter()
+        .enumerate()
+        .map(|(segment, wires)| {
+            format!(
+                "{}={}",
+                (b'a' + segment as u8) as char,
+                wires.iter().collect::<String>()
+            )
+        })
+        .join(" ")
+}
+
+pub fn part1(input: &[SingleInput]) -> usize {
+    input.iter().fold(0, |acc, input| {
+        let count = input
+            .1
+            .iter()
+            .filter(|&segments| [2, 3, 4, 7].contains(&segments.len()))
+            .count();
+        overflow::add(Day8::DAY, "count of easy digits", acc, count)
+    })
+}
+pub fn part2(input: &[SingleInput]) -> usize {
+    input.iter().fold(0, |acc, input| {
+        let mut candidates = vec![vec!['a', 'b', 'c', 'd', 'e', 'f', 'g']; 7];
+
+        // discard impossible positions
+        for pattern in input.0.iter() {
+            let mut retain = |segment_no: usize, input: &String| {
+                candidates[segment_no].retain(|n| !input.chars().contains(n));
+            };
+
+            if pattern.len() == 2 {
+                retain(0, pattern);
+                retain(1, pattern);
+                retain(3, pattern);
+                retain(4, pattern);
+                retain(6, pattern);
+            }
+
+            if pattern.len() == 3 {
+                retain(1, pattern);
+                retain(3, pattern);
+                retain(4, pattern);
+                retain(6, pattern);
+            }
+
+            if pattern.len() == 4 {
+                retain(0, pattern);
+                retain(4, pattern);
+                retain(6, pattern);
+            }
+        }
+
+        trace!(
+            Day8::DAY,
+            Level::Debug,
+            "candidates by pattern lengths: {}",
+            describe(&candidates)
+        );
+
+        // some nasty heuristics
+        let to_delete = candidates[6].clone();
+        candidates[3].retain(|&n| !to_delete.contains(&n));
+        let to_delete = candidates[0].clone();
+        candidates[2].retain(|&n| !to_delete.contains(&n));
+
+        trace!(
+            Day8::DAY,
+            Level::Debug,
+            "candidates by exclusion: {}",
+            describe(&candidates)
+        );
+
+        // even more heuristics
+        // given segment count is same across all inputs
+        let mut retain_with_count = |segment_no: usize, count| {
+            candidates[segment_no].retain(|&n| {
+                input
+                    .0
+                    .iter()
+                    .filter(|pattern| pattern.chars().contains(&n))
+                    .count()
+                    == count
+            });
+        };
+
+        retain_with_count(3, 7);
+        retain_with_count(1, 6);
+        retain_with_count(2, 8);
+        retain_with_count(5, 9);
+        retain_with_count(4, 4);
+        retain_with_count(6, 7);
+        trace!(
+            Day8::DAY,
+            Level::Debug,
+            "candidates by segment counts: {}",
+            describe(&candidates)
+        );
+
+        let value = input
+            .1
+            .iter()
+            .fold(String::new(), |sum, digit| {
+                sum + if digit.len() == 2 {
+                    "1"
+                } else if digit.len() == 3 {
+                    "7"
+                } else if digit.len() == 4 {
+                    "4"
+                } else if digit.len() == 7 {
+                    "8"
+                } else if digit.len() == 5 && digit.chars().contains(candidates[1].first().unwrap())
+                {
+                    "5"
+                } else if digit.len() == 5 && digit.chars().contains(candidates[4].first().unwrap())
+                {
+                    "2"
+                } else if digit.len() == 5 {
+                    "3"
+                } else if digit.len() == 6
+                    && !digit.chars().contains(candidates[3].first().unwrap())
+                {
+                    "0"
+                } else if digit.len() == 6 && digit.chars().contains(candidates[2].first().unwrap())
+                {
+                    "9"
+                } else {
+                    "6"
+                }
+            })
+            .parse::<usize>()
+            .unwrap();
+        trace!(
+            Day8::DAY,
+            Level::Info,
+            "{} decodes to {}",
+            input.1.join(" "),
+            value
+        );
+        overflow::add(Day8::DAY, "sum of output values", acc, value)
+    })
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+    const METADATA: Metadata = Metadata {
+        title: "Seven Segment Search",
+        part1: "appearances of 1, 4, 7 and 8 in the outputs",
+        part2: "sum of the decoded outputs",
+        params: &[],
+        visual: false,
+    };
+
+    type Input = AllInput;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        read_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let input = read_input(&fs::read_to_string("inputs/day8/input").unwrap());
+        assert_eq!(part1(&input), 247);
+        assert_eq!(part2(&input), 933305);
+    }
+}
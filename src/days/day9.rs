@@ -0,0 +1,120 @@
+use crate::days::{Metadata, Solution};
+use crate::overflow;
+use crate::prelude::*;
+use hashbrown::HashSet;
+use itertools::Itertools;
+
+pub fn read_input(input: &str) -> Vec<Vec<u8>> {
+    input
+        .lines()
+        .map(|line| line.chars().map(|point| point as u8 - b'0').collect_vec())
+        .collect_vec()
+}
+
+pub fn part1(input: &[Vec<u8>]) -> i32 {
+    input.iter().enumerate().fold(0, |acc, (x, row)| {
+        let risk = row
+            .iter()
+            .enumerate()
+            .filter(|&(y, &point)| {
+                !(x >= 1 && point >= input[x - 1][y]
+                    || x < input.len() - 1 && point >= input[x + 1][y]
+                    || y >= 1 && point >= input[x][y - 1]
+                    || y < row.len() - 1 && point >= input[x][y + 1])
+            })
+            .fold(0, |acc, (_, point)| {
+                overflow::add(Day9::DAY, "sum of risk levels", acc, *point as i32 + 1)
+            });
+        overflow::add(Day9::DAY, "sum of risk levels", acc, risk)
+    })
+}
+
+fn flood_basin(
+    visited: &mut HashSet<(usize, usize)>,
+    area: &[Vec<u8>],
+    x: usize,
+    y: usize,
+) -> usize {
+    if !visited.insert((x, y)) || area[x][y] == 9 {
+        return 0;
+    }
+
+    let mut sum = 1;
+    if x >= 1 {
+        sum += flood_basin(visited, area, x - 1, y);
+    }
+
+    if x < area.len() - 1 {
+        sum += flood_basin(visited, area, x + 1, y);
+    }
+
+    if y >= 1 {
+        sum += flood_basin(visited, area, x, y - 1);
+    }
+
+    if y < area.first().unwrap().len() - 1 {
+        sum += flood_basin(visited, area, x, y + 1);
+    }
+
+    sum
+}
+pub fn part2(input: &[Vec<u8>]) -> usize {
+    let mut visited: HashSet<(usize, usize)> = HashSet::new();
+    let mut basins: Vec<usize> = Vec::new();
+
+    for (x, row) in input.iter().enumerate() {
+        for (y, _) in row.iter().enumerate() {
+            let basin = flood_basin(&mut visited, input, x, y);
+            if basin > 0 {
+                basins.push(basin);
+            }
+        }
+    }
+    overflow::product(
+        Day9::DAY,
+        "product of basin sizes",
+        basins.iter().sorted().rev().take(3).copied(),
+    )
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+    const METADATA: Metadata = Metadata {
+        title: "Smoke Basin",
+        part1: "sum of the risk levels of the low points",
+        part2: "product of the three largest basin sizes",
+        params: &[],
+        visual: false,
+    };
+
+    type Input = Vec<Vec<u8>>;
+    type Part1 = i32;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Self::Input {
+        read_input(input)
+    }
+
+    fn part1(input: &Self::Input) -> Self::Part1 {
+        part1(input)
+    }
+
+    fn part2(input: &Self::Input) -> Self::Part2 {
+        part2(input)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_solution() {
+        let input = read_input(&fs::read_to_string("inputs/day9/input").unwrap());
+        assert_eq!(part1(&input), 594);
+        assert_eq!(part2(&input), 858494);
+    }
+}
@@ -0,0 +1,143 @@
+#[cfg(feature = "std")]
+use crate::outcome::Failure;
+use crate::prelude::*;
+use core::fmt::Display;
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::io::BufRead;
+
+pub mod day1;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod day17;
+pub mod day2;
+pub mod day3;
+pub mod day4;
+pub mod day5;
+pub mod day6;
+pub mod day7;
+pub mod day8;
+pub mod day9;
+
+/// What a day is about, as listed by `aoc list`.
+#[derive(Debug, Clone, Copy)]
+pub struct Metadata {
+    pub title: &'static str,
+    pub part1: &'static str,
+    pub part2: &'static str,
+    /// Parameters accepted by `part_with`, by part, e.g. `(1, "STEPS")`.
+    pub params: &'static [(u8, &'static str)],
+    /// Whether the day draws something, be it an animation or the answer itself.
+    pub visual: bool,
+}
+
+/// Common interface of every day, so the runner can drive them uniformly.
+pub trait Solution {
+    const DAY: u8;
+    const METADATA: Metadata;
+
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Self::Input;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    /// Assumptions of the solvers the input breaks, all of them, to be reported before solving.
+    fn validate(_input: &Self::Input) -> Vec<String> {
+        Vec::new()
+    }
+
+    /// Rendered frames of the simulation, for days that have something worth watching.
+    fn frames(_input: &Self::Input) -> Option<Box<dyn Iterator<Item = String> + '_>> {
+        None
+    }
+
+    /// Solves a part with other parameters than the puzzle's, e.g. a different number of steps.
+    fn part_with(_input: &Self::Input, part: u8, _params: &[&str]) -> Result<String, String> {
+        Err(format!(
+            "Part {} of day {} takes no parameters",
+            part,
+            Self::DAY
+        ))
+    }
+
+    /// Solves both parts in a single pass over the input with bounded memory, for days that can.
+    #[cfg(feature = "std")]
+    fn stream(_reader: &mut dyn BufRead) -> Option<Result<(String, String), Failure>> {
+        None
+    }
+
+    /// The answer as drawn, for days whose answer is read off a picture rather than the number.
+    fn drawing(_input: &Self::Input) -> Option<String> {
+        None
+    }
+
+    /// Human readable form of the parsed input, for days where it's worth looking at.
+    fn show(_input: &Self::Input) -> Option<String> {
+        None
+    }
+}
+
+/// Parses the only parameter of a part, like the number of steps to simulate.
+pub fn single_param<T: FromStr>(params: &[&str]) -> Result<T, String> {
+    match params {
+        [param] => param
+            .parse()
+            .map_err(|_| format!("Invalid parameter: {}", param)),
+        _ => Err(format!("Expected a single parameter, got {}", params.len())),
+    }
+}
+
+/// Something to be done with a day whose concrete type is only known at runtime.
+pub trait Visitor {
+    type Output;
+
+    fn visit<S: Solution>(self) -> Self::Output;
+}
+
+pub const DAYS: core::ops::RangeInclusive<u8> = 1..=17;
+
+struct Describe;
+
+impl Visitor for Describe {
+    type Output = Metadata;
+
+    fn visit<S: Solution>(self) -> Self::Output {
+        S::METADATA
+    }
+}
+
+pub fn metadata(day: u8) -> Option<Metadata> {
+    with_day(day, Describe)
+}
+
+/// Runs the visitor against the given day, if it's implemented.
+pub fn with_day<V: Visitor>(day: u8, visitor: V) -> Option<V::Output> {
+    Some(match day {
+        1 => visitor.visit::<day1::Day1>(),
+        2 => visitor.visit::<day2::Day2>(),
+        3 => visitor.visit::<day3::Day3>(),
+        4 => visitor.visit::<day4::Day4>(),
+        5 => visitor.visit::<day5::Day5>(),
+        6 => visitor.visit::<day6::Day6>(),
+        7 => visitor.visit::<day7::Day7>(),
+        8 => visitor.visit::<day8::Day8>(),
+        9 => visitor.visit::<day9::Day9>(),
+        10 => visitor.visit::<day10::Day10>(),
+        11 => visitor.visit::<day11::Day11>(),
+        12 => visitor.visit::<day12::Day12>(),
+        13 => visitor.visit::<day13::Day13>(),
+        14 => visitor.visit::<day14::Day14>(),
+        15 => visitor.visit::<day15::Day15>(),
+        16 => visitor.visit::<day16::Day16>(),
+        17 => visitor.visit::<day17::Day17>(),
+        _ => return None,
+    })
+}
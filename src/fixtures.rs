@@ -0,0 +1,33 @@
+//! Pseudo-random inputs for checking a faster solver against the way a day was first solved.
+
+use core::fmt::Debug;
+
+/// A linear congruential generator, so that every run checks the same inputs.
+pub struct Random(u64);
+
+impl Random {
+    pub fn new(seed: u64) -> Self {
+        Random(seed)
+    }
+
+    /// A number below `bound`, taken from the high bits, which cycle slowest.
+    pub fn below(&mut self, bound: u64) -> u64 {
+        self.0 = self
+            .0
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        (self.0 >> 33) % bound
+    }
+}
+
+/// Asserts that the solver gives the same answer as the reference on every input.
+#[track_caller]
+pub fn assert_agrees<I: Debug, T: PartialEq + Debug>(
+    inputs: impl IntoIterator<Item = I>,
+    solver: impl Fn(&I) -> T,
+    reference: impl Fn(&I) -> T,
+) {
+    for input in inputs {
+        assert_eq!(solver(&input), reference(&input), "{:?}", input);
+    }
+}
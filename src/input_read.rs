@@ -1,9 +1,37 @@
-use std::io::BufRead;
+use crate::prelude::*;
+#[cfg(feature = "std")]
+use std::fs;
+#[cfg(feature = "std")]
+use std::path::PathBuf;
 
-pub fn read_to_2d_byte_array<R: BufRead>(reader: R) -> Vec<Vec<u8>> {
-    reader
+pub fn read_to_2d_byte_array(input: &str) -> Vec<Vec<u8>> {
+    input
         .lines()
-        .filter_map(Result::ok)
         .map(|line| line.chars().map(|ch| ch as u8 - b'0').collect())
         .collect()
 }
+
+#[cfg(feature = "std")]
+pub fn input_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("inputs/day{}/input", day))
+}
+
+/// Example inputs of the day, i.e. files named `example*` next to the input.
+#[cfg(feature = "std")]
+pub fn example_paths(day: u8) -> Vec<PathBuf> {
+    let mut paths: Vec<PathBuf> = fs::read_dir(format!("inputs/day{}", day))
+        .map(|entries| {
+            entries
+                .map_while(Result::ok)
+                .map(|entry| entry.path())
+                .filter(|path| {
+                    path.file_name()
+                        .and_then(|name| name.to_str())
+                        .is_some_and(|name| name.starts_with("example"))
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+    paths.sort();
+    paths
+}
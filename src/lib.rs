@@ -1 +1,16 @@
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+
+extern crate alloc;
+
+pub mod answers;
+pub mod days;
+#[cfg(test)]
+mod fixtures;
 pub mod input_read;
+pub mod memo;
+#[cfg(feature = "std")]
+pub mod outcome;
+pub mod overflow;
+mod prelude;
+pub mod terminal;
+pub mod trace;
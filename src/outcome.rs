@@ -0,0 +1,229 @@
+//! How the binaries end: answers go to stdout, diagnostics to stderr, and the exit status tells
+//! what went wrong, so scripts can tell a bad input from a bug in a solver.
+
+use crate::days::Solution;
+use std::cell::Cell;
+use std::fmt;
+use std::io::{self, BufRead, Read, Write};
+use std::panic::{self, AssertUnwindSafe};
+use std::process;
+use std::sync::Once;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Failure {
+    /// Anything not covered below, like a download gone wrong.
+    Other(String),
+    Usage(String),
+    Parse(String),
+    /// The input parsed, but a part has no answer for it.
+    Unsolvable(String),
+    /// An answer differs from the accepted one.
+    Mismatch(String),
+    Io(String),
+}
+
+impl Failure {
+    pub fn code(&self) -> i32 {
+        match self {
+            Failure::Other(_) => 1,
+            Failure::Usage(_) => 2,
+            Failure::Parse(_) => 3,
+            Failure::Unsolvable(_) => 4,
+            Failure::Mismatch(_) => 5,
+            Failure::Io(_) => 6,
+        }
+    }
+}
+
+impl fmt::Display for Failure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Failure::Other(message)
+            | Failure::Usage(message)
+            | Failure::Parse(message)
+            | Failure::Unsolvable(message)
+            | Failure::Mismatch(message)
+            | Failure::Io(message) => f.write_str(message),
+        }
+    }
+}
+
+impl From<String> for Failure {
+    fn from(message: String) -> Self {
+        Failure::Other(message)
+    }
+}
+
+thread_local! {
+    static CATCHING: Cell<bool> = const { Cell::new(false) };
+}
+
+static QUIET_HOOK: Once = Once::new();
+
+/// Runs the closure, turning a panic into its message instead of a report with a backtrace.
+pub fn catch<T>(f: impl FnOnce() -> T) -> Result<T, String> {
+    QUIET_HOOK.call_once(|| {
+        let report = panic::take_hook();
+        panic::set_hook(Box::new(move |info| {
+            if !CATCHING.with(Cell::get) {
+                report(info);
+            }
+        }));
+    });
+
+    let catching = CATCHING.with(|catching| catching.replace(true));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CATCHING.with(|cell| cell.set(catching));
+    result.map_err(|payload| {
+        payload
+            .downcast_ref::<&str>()
+            .map(|message| message.to_string())
+            .or_else(|| payload.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "unknown failure".to_owned())
+    })
+}
+
+pub fn parse_failure<S: Solution>(err: impl fmt::Display) -> Failure {
+    Failure::Parse(format!("Day {}: cannot parse the input: {}", S::DAY, err))
+}
+
+/// Lines of a streamed input, with those that can't be read failing. Lines are numbered from 1,
+/// as editors do, here and in the line numbers of every day's parse errors.
+pub fn lines<S: Solution>(
+    reader: impl BufRead,
+) -> impl Iterator<Item = Result<(usize, String), Failure>> {
+    reader.lines().enumerate().map(|(i, line)| {
+        line.map(|line| (i + 1, line)).map_err(|err| {
+            Failure::Io(format!(
+                "Day {}: cannot read line {}: {}",
+                S::DAY,
+                i + 1,
+                err
+            ))
+        })
+    })
+}
+
+/// Parses the input, checking it holds up to what the solvers assume.
+pub fn parse<S: Solution>(input: &str) -> Result<S::Input, Failure> {
+    let input = catch(|| S::parse(input)).map_err(parse_failure::<S>)?;
+
+    let violations = S::validate(&input);
+    if violations.is_empty() {
+        Ok(input)
+    } else {
+        Err(Failure::Parse(format!(
+            "Day {}: invalid input:\n    {}",
+            S::DAY,
+            violations.join("\n    ")
+        )))
+    }
+}
+
+/// Runs whatever else a day does with its input, like animating it, failing as unsolvable on a panic.
+pub fn guard<S: Solution, T>(f: impl FnOnce() -> T) -> Result<T, Failure> {
+    catch(f).map_err(|err| Failure::Unsolvable(format!("Day {}: {}", S::DAY, err)))
+}
+
+/// Stdout is gone, e.g. piped into `head` that has seen enough.
+pub fn write_failure(err: io::Error) -> Failure {
+    Failure::Io(format!("Cannot write the output: {}", err))
+}
+
+pub fn part<S: Solution>(input: &S::Input, part: u8) -> Result<String, Failure> {
+    catch(|| match part {
+        1 => S::part1(input).to_string(),
+        _ => S::part2(input).to_string(),
+    })
+    .map_err(|err| Failure::Unsolvable(format!("Day {}, part {}: {}", S::DAY, part, err)))
+}
+
+/// Solves a part with other parameters than the puzzle's; those the day can't take are misuse.
+pub fn part_with<S: Solution>(
+    input: &S::Input,
+    part: u8,
+    params: &[&str],
+) -> Result<String, Failure> {
+    catch(|| S::part_with(input, part, params))
+        .map_err(|err| Failure::Unsolvable(format!("Day {}, part {}: {}", S::DAY, part, err)))?
+        .map_err(|err| Failure::Usage(format!("Day {}, part {}: {}", S::DAY, part, err)))
+}
+
+pub fn exit(result: Result<(), Failure>) -> ! {
+    match result {
+        Ok(()) => process::exit(0),
+        Err(failure) => {
+            eprintln!("{}", failure);
+            process::exit(failure.code())
+        }
+    }
+}
+
+/// Writes both answers, and the drawing of days that have one, as the single day binaries do.
+fn solve<S: Solution>(input: &str, output: &mut impl Write) -> Result<(), Failure> {
+    let input = parse::<S>(input)?;
+    writeln!(output, "Day {}, part 1: {}", S::DAY, part::<S>(&input, 1)?).map_err(write_failure)?;
+    writeln!(output, "Day {}, part 2: {}", S::DAY, part::<S>(&input, 2)?).map_err(write_failure)?;
+    if let Some(drawing) = guard::<S, _>(|| S::drawing(&input))? {
+        writeln!(output, "{}", drawing).map_err(write_failure)?;
+    }
+    Ok(())
+}
+
+fn solve_stdin<S: Solution>() -> Result<(), Failure> {
+    let mut input = String::new();
+    io::stdin()
+        .read_to_string(&mut input)
+        .map_err(|err| Failure::Io(format!("Cannot read the input: {}", err)))?;
+
+    solve::<S>(&input, &mut io::stdout().lock())
+}
+
+/// Solves the day on the standard input, as the single day binaries do.
+pub fn main<S: Solution>() -> ! {
+    exit(solve_stdin::<S>())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days::day13::Day13;
+    use crate::days::day4::Day4;
+    use std::fs;
+
+    #[test]
+    fn test_failures() {
+        assert_eq!(catch(|| 42), Ok(42));
+        assert_eq!(
+            guard::<Day4, ()>(|| panic!("nothing to animate")),
+            Err(Failure::Unsolvable("Day 4: nothing to animate".to_owned()))
+        );
+        assert!(matches!(parse::<Day4>(""), Err(Failure::Parse(_))));
+
+        let board = "1 2 3 4 5\n".repeat(5);
+        let input = parse::<Day4>(&format!("8,9\n\n{}", board)).unwrap();
+        let failure = part::<Day4>(&input, 1).unwrap_err();
+        assert_eq!(
+            failure,
+            Failure::Unsolvable("Day 4, part 1: no solution!".to_owned())
+        );
+        assert_eq!(failure.code(), 4);
+    }
+
+    #[test]
+    fn test_drawing() {
+        let mut output = Vec::new();
+        solve::<Day13>(
+            &fs::read_to_string("inputs/day13/input").unwrap(),
+            &mut output,
+        )
+        .unwrap();
+        let output = String::from_utf8(output).unwrap();
+        assert!(output.starts_with("Day 13, part 1: 687\nDay 13, part 2: 98\n####  ##  #  #"));
+        assert_eq!(output.lines().count(), 8);
+
+        // the dot ends up 4 above the sheet, with nothing to draw it on
+        let failure = solve::<Day13>("1,10\n\nfold along y=3\n", &mut Vec::new()).unwrap_err();
+        assert!(matches!(failure, Failure::Parse(_)), "{:?}", failure);
+    }
+}
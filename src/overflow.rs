@@ -0,0 +1,120 @@
+//! Arithmetic for the solvers' accumulations. With the `checked` feature enabled, an overflow
+//! panics with the day and operation named instead of silently wrapping into a wrong answer.
+//! Without it, they behave like plain arithmetic: panicking in debug builds, wrapping in release.
+
+use core::fmt;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Overflow {
+    pub day: u8,
+    pub operation: &'static str,
+}
+
+impl fmt::Display for Overflow {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {}: overflow in {}", self.day, self.operation)
+    }
+}
+
+pub trait Checked: Copy {
+    const ZERO: Self;
+    const ONE: Self;
+
+    fn add(self, rhs: Self) -> Option<Self>;
+    fn sub(self, rhs: Self) -> Option<Self>;
+    fn mul(self, rhs: Self) -> Option<Self>;
+    fn wrapping_add(self, rhs: Self) -> Self;
+    fn wrapping_sub(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_checked {
+    ($($t:ty),*) => {
+        $(impl Checked for $t {
+            const ZERO: Self = 0;
+            const ONE: Self = 1;
+
+            fn add(self, rhs: Self) -> Option<Self> {
+                self.checked_add(rhs)
+            }
+            fn sub(self, rhs: Self) -> Option<Self> {
+                self.checked_sub(rhs)
+            }
+            fn mul(self, rhs: Self) -> Option<Self> {
+                self.checked_mul(rhs)
+            }
+            fn wrapping_add(self, rhs: Self) -> Self {
+                <$t>::wrapping_add(self, rhs)
+            }
+            fn wrapping_sub(self, rhs: Self) -> Self {
+                <$t>::wrapping_sub(self, rhs)
+            }
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$t>::wrapping_mul(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_checked!(i32, i64, u32, u64, usize);
+
+#[inline]
+fn check<T>(
+    result: Option<T>,
+    fallback: impl FnOnce() -> T,
+    day: u8,
+    operation: &'static str,
+) -> T {
+    if cfg!(any(feature = "checked", debug_assertions)) {
+        result.unwrap_or_else(|| panic!("{}", Overflow { day, operation }))
+    } else {
+        result.unwrap_or_else(fallback)
+    }
+}
+
+#[inline]
+pub fn add<T: Checked>(day: u8, operation: &'static str, a: T, b: T) -> T {
+    check(a.add(b), || a.wrapping_add(b), day, operation)
+}
+
+#[inline]
+pub fn sub<T: Checked>(day: u8, operation: &'static str, a: T, b: T) -> T {
+    check(a.sub(b), || a.wrapping_sub(b), day, operation)
+}
+
+#[inline]
+pub fn mul<T: Checked>(day: u8, operation: &'static str, a: T, b: T) -> T {
+    check(a.mul(b), || a.wrapping_mul(b), day, operation)
+}
+
+pub fn sum<T: Checked, I: IntoIterator<Item = T>>(day: u8, operation: &'static str, iter: I) -> T {
+    iter.into_iter()
+        .fold(T::ZERO, |sum, value| add(day, operation, sum, value))
+}
+
+pub fn product<T: Checked, I: IntoIterator<Item = T>>(
+    day: u8,
+    operation: &'static str,
+    iter: I,
+) -> T {
+    iter.into_iter()
+        .fold(T::ONE, |product, value| mul(day, operation, product, value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_overflow() {
+        assert_eq!(add(2, "pos + val", 2, 3), 5);
+        assert_eq!(product(16, "product", [2u64, 3, 7]), 42);
+
+        let result = std::panic::catch_unwind(|| mul(2, "pos * depth", i32::MAX, 2));
+        if cfg!(any(feature = "checked", debug_assertions)) {
+            assert!(result.is_err());
+        } else {
+            assert_eq!(result.unwrap(), -2);
+        }
+    }
+}
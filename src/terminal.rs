@@ -0,0 +1,76 @@
+use crate::prelude::*;
+use core::fmt::Display;
+#[cfg(feature = "std")]
+use std::io::{self, Write};
+#[cfg(feature = "std")]
+use std::thread;
+#[cfg(feature = "std")]
+use std::time::Duration;
+
+pub const CLEAR_SCREEN: &str = "\x1b[2J";
+pub const CLEAR_LINE: &str = "\x1b[K";
+pub const CLEAR_BELOW: &str = "\x1b[J";
+pub const CURSOR_HOME: &str = "\x1b[H";
+pub const HIDE_CURSOR: &str = "\x1b[?25l";
+pub const SHOW_CURSOR: &str = "\x1b[?25h";
+const RESET: &str = "\x1b[0m";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Color {
+    Red = 31,
+    Green = 32,
+    Yellow = 33,
+    Blue = 34,
+    Magenta = 35,
+    Cyan = 36,
+    White = 37,
+    Gray = 90,
+    BrightWhite = 97,
+}
+
+pub fn paint<T: Display>(text: T, color: Color) -> String {
+    format!("\x1b[{}m{}{}", color as u8, text, RESET)
+}
+
+pub fn bold<T: Display>(text: T) -> String {
+    format!("\x1b[1m{}{}", text, RESET)
+}
+
+/// Draws the frames one over another, waiting `delay` after each of them.
+#[cfg(feature = "std")]
+pub fn animate<W, I>(out: &mut W, frames: I, delay: Duration) -> io::Result<()>
+where
+    W: Write,
+    I: Iterator<Item = String>,
+{
+    write!(out, "{}{}", HIDE_CURSOR, CLEAR_SCREEN)?;
+    for frame in frames {
+        write!(out, "{}", CURSOR_HOME)?;
+        for line in frame.lines() {
+            writeln!(out, "{}{}", line, CLEAR_LINE)?;
+        }
+        write!(out, "{}", CLEAR_BELOW)?;
+        out.flush()?;
+        thread::sleep(delay);
+    }
+    write!(out, "{}", SHOW_CURSOR)?;
+    out.flush()
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_animate() {
+        let mut out = Vec::new();
+        let frames = vec!["a\nb".to_owned(), "c".to_owned()];
+        animate(&mut out, frames.into_iter(), Duration::ZERO).unwrap();
+
+        let out = String::from_utf8(out).unwrap();
+        assert_eq!(
+            out,
+            "\x1b[?25l\x1b[2J\x1b[Ha\x1b[K\nb\x1b[K\n\x1b[J\x1b[Hc\x1b[K\n\x1b[J\x1b[?25h"
+        );
+    }
+}
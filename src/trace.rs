@@ -0,0 +1,97 @@
+//! Explanations of what the solvers do, step by step. Each day is a separate target with its own
+//! level; while a day's tracing is off, its events cost a single relaxed load and are never formatted.
+
+use crate::prelude::*;
+use core::fmt;
+use core::str::FromStr;
+use core::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("Unknown trace level: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        })
+    }
+}
+
+static LEVELS: [AtomicU8; 26] = [const { AtomicU8::new(0) }; 26];
+
+/// Turns on the events of the day up to the given level; days past Christmas have none.
+pub fn enable(day: u8, level: Level) {
+    if let Some(day_level) = LEVELS.get(day as usize) {
+        day_level.store(level as u8, Ordering::Relaxed);
+    }
+}
+
+pub fn disable(day: u8) {
+    if let Some(day_level) = LEVELS.get(day as usize) {
+        day_level.store(0, Ordering::Relaxed);
+    }
+}
+
+#[inline]
+pub fn enabled(day: u8, level: Level) -> bool {
+    LEVELS
+        .get(day as usize)
+        .is_some_and(|day_level| day_level.load(Ordering::Relaxed) >= level as u8)
+}
+
+/// Prints the event to stderr; without std, there's nowhere to print it to.
+pub fn emit(day: u8, level: Level, args: fmt::Arguments) {
+    #[cfg(feature = "std")]
+    eprintln!("[day{} {}] {}", day, level, args);
+    #[cfg(not(feature = "std"))]
+    let _ = (day, level, args);
+}
+
+/// Emits an event of the day, e.g. `trace!(4, Level::Info, "board {} wins", i)`.
+#[macro_export]
+macro_rules! trace {
+    ($day:expr, $level:expr, $($arg:tt)+) => {
+        if $crate::trace::enabled($day, $level) {
+            $crate::trace::emit($day, $level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_levels() {
+        assert!(!enabled(25, Level::Info));
+        enable(25, "debug".parse().unwrap());
+        assert!(enabled(25, Level::Info));
+        assert!(enabled(25, Level::Debug));
+        assert!(!enabled(25, Level::Trace));
+        assert!(!enabled(24, Level::Info));
+        disable(25);
+        assert!(!enabled(25, Level::Info));
+
+        enable(99, Level::Trace);
+        assert!(!enabled(99, Level::Info));
+    }
+}